@@ -0,0 +1,43 @@
+// This shader computes the vignette, film grain, desaturation and double vision effects, each
+// saturating at an intensity of 1
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput;
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+
+struct PostEffects {
+    vignette: f32,
+    grain: f32,
+    desaturation: f32,
+    double_vision: f32,
+    time: f32,
+}
+
+@group(0) @binding(2) var<uniform> settings: PostEffects;
+
+fn hash(p: vec2<f32>) -> f32 {
+    return fract(sin(dot(p, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // A copy of the screen slowly drifting around the original
+    let double_vision = clamp(settings.double_vision, 0.0, 1.0);
+    let drift = vec2<f32>(sin(settings.time * 0.7), cos(settings.time * 0.5) * 0.3) * 0.03;
+    let original = textureSample(screen_texture, texture_sampler, in.uv).rgb;
+    let ghost = textureSample(screen_texture, texture_sampler, in.uv + drift * double_vision).rgb;
+    var color = mix(original, ghost, double_vision * 0.5);
+
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    color = mix(color, vec3<f32>(luminance), clamp(settings.desaturation, 0.0, 1.0));
+
+    let noise = hash(in.uv * 1000.0 + fract(settings.time) * 100.0) - 0.5;
+    color += noise * clamp(settings.grain, 0.0, 1.0) * 0.25;
+
+    let uv = (in.uv - vec2f(0.5, 0.5)) * 2.;
+    let vignette = smoothstep(0.4, 1.4, length(uv)) * clamp(settings.vignette, 0.0, 1.0);
+    color *= 1.0 - vignette;
+
+    return vec4<f32>(color, 1.0);
+}
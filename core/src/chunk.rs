@@ -1,4 +1,5 @@
 use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::system::SystemParam;
 use bevy::ecs::world::DeferredWorld;
 use serde::{Deserialize, Serialize};
 
@@ -8,12 +9,26 @@ use crate::physics::GameLayer;
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_observer(on_spawn_chunk)
-        .add_observer(on_despawn_chunk);
+    app.init_resource::<ChunkIndex>()
+        .add_observer(on_spawn_chunk)
+        .add_observer(on_despawn_chunk)
+        .add_systems(
+            PostUpdate,
+            (
+                reindex_moved_chunks,
+                disable_dormant_colliders,
+                reload_mutation_pool_handles,
+            ),
+        );
 }
 
 pub const CHUNK_SIZE: f32 = 5.;
 
+/// Grid cell of a chunk at `translation`
+pub fn grid_cell(translation: Vec3) -> IVec2 {
+    (translation.xz() / CHUNK_SIZE).round().as_ivec2()
+}
+
 #[derive(Default, Component, Reflect)]
 #[require(Transform, Visibility, Sensor, LevelComponent)]
 #[reflect(Component)]
@@ -21,10 +36,169 @@ pub struct Chunk {
     pub descriptor_name: String,
 }
 
-#[derive(Component, Debug, Default, Serialize, Deserialize, Reflect, Clone, Copy)]
+#[derive(
+    Component, Debug, Default, Serialize, Deserialize, Reflect, Clone, Copy, PartialEq, Eq, Hash,
+)]
 #[reflect(Component)]
+#[component(on_insert, on_replace)]
 pub struct ChunkId(pub u32);
 
+impl ChunkId {
+    fn on_insert(mut world: DeferredWorld, hook: HookContext) {
+        let id = *world.get::<ChunkId>(hook.entity).unwrap();
+        let cell = world
+            .get::<Transform>(hook.entity)
+            .map(|transform| grid_cell(transform.translation));
+        if let Some(mut index) = world.get_resource_mut::<ChunkIndex>() {
+            index.insert(id, hook.entity, cell);
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, hook: HookContext) {
+        let id = *world.get::<ChunkId>(hook.entity).unwrap();
+        if let Some(mut index) = world.get_resource_mut::<ChunkIndex>() {
+            index.remove(id, hook.entity);
+        }
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+/// Maps [`ChunkId`]s and grid cells to chunk entities, maintained by the [`ChunkId`] hooks
+///
+/// Use [`Chunks`] to move chunks around while keeping the index up to date.
+pub struct ChunkIndex {
+    entities: HashMap<ChunkId, Entity>,
+    cells: HashMap<IVec2, Vec<Entity>>,
+    entity_cells: HashMap<Entity, IVec2>,
+}
+
+impl ChunkIndex {
+    /// Entity of the chunk with the given id
+    pub fn entity(&self, id: ChunkId) -> Option<Entity> {
+        self.entities.get(&id).copied()
+    }
+
+    /// All chunks occupying the grid cell `cell`, chunks may be stacked on the same cell
+    pub fn at(&self, cell: IVec2) -> &[Entity] {
+        self.cells.get(&cell).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Grid cell occupied by the chunk `entity`
+    pub fn cell_of(&self, entity: Entity) -> Option<IVec2> {
+        self.entity_cells.get(&entity).copied()
+    }
+
+    fn insert(&mut self, id: ChunkId, entity: Entity, cell: Option<IVec2>) {
+        if let Some(previous) = self.entities.insert(id, entity)
+            && previous != entity
+        {
+            warn!("Chunk id {} is used by {previous} and {entity}", id.0);
+        }
+        if let Some(cell) = cell {
+            self.set_cell(entity, cell);
+        }
+    }
+
+    fn remove(&mut self, id: ChunkId, entity: Entity) {
+        if self.entities.get(&id) == Some(&entity) {
+            self.entities.remove(&id);
+        }
+        if let Some(cell) = self.entity_cells.remove(&entity) {
+            self.remove_from_cell(entity, cell);
+        }
+    }
+
+    fn set_cell(&mut self, entity: Entity, cell: IVec2) {
+        match self.entity_cells.insert(entity, cell) {
+            Some(previous) if previous == cell => return,
+            Some(previous) => self.remove_from_cell(entity, previous),
+            None => {}
+        }
+        self.cells.entry(cell).or_default().push(entity);
+    }
+
+    fn remove_from_cell(&mut self, entity: Entity, cell: IVec2) {
+        if let Some(entities) = self.cells.get_mut(&cell) {
+            entities.retain(|e| *e != entity);
+            if entities.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+}
+
+#[derive(SystemParam)]
+/// O(1) chunk lookups and mutations backed by the [`ChunkIndex`]
+pub struct Chunks<'w, 's> {
+    index: ResMut<'w, ChunkIndex>,
+    transforms: Query<'w, 's, &'static mut Transform, With<Chunk>>,
+}
+
+impl Chunks<'_, '_> {
+    /// Entity of the chunk with the given id
+    pub fn entity(&self, id: ChunkId) -> Option<Entity> {
+        self.index.entity(id)
+    }
+
+    /// All chunks occupying the grid cell `cell`
+    pub fn at(&self, cell: IVec2) -> &[Entity] {
+        self.index.at(cell)
+    }
+
+    /// Grid cell occupied by the chunk with the given id
+    pub fn cell(&self, id: ChunkId) -> Option<IVec2> {
+        self.index.cell_of(self.index.entity(id)?)
+    }
+
+    /// Move the chunk with the given id to the grid cell `cell`, returns false if it doesn't exist
+    pub fn move_to(&mut self, id: ChunkId, cell: IVec2) -> bool {
+        let Some(entity) = self.index.entity(id) else {
+            return false;
+        };
+        let Ok(mut transform) = self.transforms.get_mut(entity) else {
+            return false;
+        };
+        transform.translation.x = cell.x as f32 * CHUNK_SIZE;
+        transform.translation.z = cell.y as f32 * CHUNK_SIZE;
+        self.index.set_cell(entity, cell);
+        true
+    }
+
+    /// Swap the positions of two chunks, returns false if either of them doesn't exist
+    pub fn swap(&mut self, a: ChunkId, b: ChunkId) -> bool {
+        let (Some(entity_a), Some(entity_b)) = (self.index.entity(a), self.index.entity(b)) else {
+            return false;
+        };
+        let Ok([mut transform_a, mut transform_b]) =
+            self.transforms.get_many_mut([entity_a, entity_b])
+        else {
+            return false;
+        };
+        std::mem::swap(&mut transform_a.translation, &mut transform_b.translation);
+        let (cell_a, cell_b) = (
+            grid_cell(transform_a.translation),
+            grid_cell(transform_b.translation),
+        );
+        self.index.set_cell(entity_a, cell_a);
+        self.index.set_cell(entity_b, cell_b);
+        true
+    }
+}
+
+/// Keep the [`ChunkIndex`] cells in sync for chunks that were moved without going through
+/// [`Chunks`], e.g. by the editor or when loading a saved scene
+fn reindex_moved_chunks(
+    mut index: ResMut<ChunkIndex>,
+    chunks: Query<(Entity, &Transform), (With<ChunkId>, Changed<Transform>)>,
+) {
+    for (entity, transform) in &chunks {
+        let cell = grid_cell(transform.translation);
+        if index.cell_of(entity) != Some(cell) {
+            index.set_cell(entity, cell);
+        }
+    }
+}
+
 #[derive(Debug, Event)]
 pub struct SpawnChunk {
     pub level: Entity,
@@ -32,11 +206,81 @@ pub struct SpawnChunk {
     pub grid_position: Vec2,
     pub descriptor: Handle<ChunkDescriptor>,
     pub components: Vec<ChunkMarker>,
+    pub dormant: bool,
 }
 
 #[derive(Debug, Event)]
 pub struct DespawnChunk(pub ChunkId);
 
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+#[require(LevelComponent)]
+#[component(on_remove)]
+/// A dormant chunk stays in place, but has its colliders and sensor disabled until this component
+/// is removed. The game also hides it, the editor keeps it visible for editing.
+pub struct Dormant;
+
+impl Dormant {
+    fn on_remove(mut world: DeferredWorld, hook: HookContext) {
+        let mut entities = vec![hook.entity];
+        while let Some(entity) = entities.pop() {
+            if let Some(children) = world.get::<Children>(entity) {
+                entities.extend(children.iter());
+            }
+            if world.entity(entity).contains::<DormantCollider>() {
+                world
+                    .commands()
+                    .entity(entity)
+                    .try_remove::<(ColliderDisabled, DormantCollider)>();
+            }
+        }
+    }
+}
+
+/// Marks the colliders disabled by [`Dormant`], so colliders disabled for other reasons stay so
+#[derive(Component, Debug)]
+struct DormantCollider;
+
+/// Colliders of chunk elements (e.g. glTF scenes) may be spawned after the chunk became dormant,
+/// so they are disabled here instead of when the chunk becomes dormant
+fn disable_dormant_colliders(
+    mut commands: Commands,
+    new_dormant_chunks: Query<Entity, Added<Dormant>>,
+    new_colliders: Query<Entity, Added<Collider>>,
+    dormant_chunks: Query<(), With<Dormant>>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    colliders: Query<(), (With<Collider>, Without<ColliderDisabled>)>,
+) {
+    let chunk_colliders = new_dormant_chunks
+        .iter()
+        .flat_map(|chunk| std::iter::once(chunk).chain(children.iter_descendants(chunk)));
+    let colliders_in_dormant_chunks = new_colliders.iter().filter(|entity| {
+        std::iter::once(*entity)
+            .chain(parents.iter_ancestors(*entity))
+            .any(|ancestor| dormant_chunks.contains(ancestor))
+    });
+    for entity in chunk_colliders.chain(colliders_in_dormant_chunks) {
+        if colliders.contains(entity) {
+            commands
+                .entity(entity)
+                .try_insert((ColliderDisabled, DormantCollider));
+        }
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[require(LevelComponent)]
+#[reflect(Component, Default)]
+pub struct DormancySensorChunk {
+    /// Ids of the dormant chunks to activate
+    pub activate: Vec<ChunkId>,
+    /// Ids of the chunks to make dormant
+    pub deactivate: Vec<ChunkId>,
+    /// Whether the sensor should be preserved or removed after the first trigger
+    pub preserve_after_trigger: bool,
+}
+
 #[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
 #[require(LevelComponent)]
 #[reflect(Component, Default)]
@@ -58,8 +302,12 @@ pub struct ReplaceAssetSensorChunk {
     pub chunk: ChunkId,
     /// Name of the chunk descriptor that will be swapped-in
     pub descriptor: String,
-    /// Whether the sensor should be inverted or removed after the first swap
+    /// Whether the sensor should toggle between replacing and reverting to the previous
+    /// descriptor, or be removed after the first swap
     pub invert_after_swap: bool,
+    /// Markers replacing the ones of the replaced chunk, `None` keeps its current markers
+    #[serde(default)]
+    pub markers: Option<Vec<ChunkMarker>>,
 }
 
 impl ReplaceAssetSensorChunk {
@@ -81,6 +329,53 @@ pub struct ReplaceAssetSensorChunkHandle {
     pub asset: Handle<ChunkDescriptor>,
 }
 
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub enum DescriptorRevert {
+    /// Revert to the descriptor the chunk had before its last replacement
+    #[default]
+    Previous,
+    /// Revert to the descriptor the chunk was spawned with
+    Original,
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+pub struct RevertAssetSensorChunk {
+    /// Id of the chunk whose descriptor will be reverted
+    pub chunk: ChunkId,
+    /// Which descriptor of the chunk's [`ChunkDescriptorHistory`] to revert to
+    pub target: DescriptorRevert,
+    /// Whether the sensor should be preserved or removed after the first revert
+    pub preserve_after_revert: bool,
+}
+
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+/// Names of the descriptors a chunk had before it was replaced, oldest first
+pub struct ChunkDescriptorHistory(pub Vec<String>);
+
+impl ChunkDescriptorHistory {
+    /// Pop the descriptor name to revert to, the history is emptied when reverting to the original
+    pub fn take(&mut self, target: DescriptorRevert) -> Option<String> {
+        match target {
+            DescriptorRevert::Previous => self.0.pop(),
+            DescriptorRevert::Original => {
+                let original = self.0.first().cloned();
+                self.0.clear();
+                original
+            }
+        }
+    }
+}
+
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+#[require(LevelComponent)]
+/// Marks the children of a chunk that were spawned from its descriptor's elements
+pub struct ChunkElementInstance;
+
 #[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
 #[reflect(Component, Default)]
 #[require(LevelComponent)]
@@ -93,6 +388,192 @@ pub struct MoveChunkSensorChunk {
     pub z: i32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub enum ChunkMutation {
+    /// Swap the mutated chunk with the chunk of the given id
+    Swap(ChunkId),
+    /// Replace the descriptor of the mutated chunk with the named descriptor
+    ReplaceAsset(String),
+}
+
+impl Default for ChunkMutation {
+    fn default() -> Self {
+        ChunkMutation::Swap(ChunkId::default())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub struct WeightedChunkMutation {
+    /// Relative weight of this candidate within the pool
+    pub weight: f32,
+    pub mutation: ChunkMutation,
+}
+
+impl Default for WeightedChunkMutation {
+    fn default() -> Self {
+        Self {
+            weight: 1.,
+            mutation: ChunkMutation::default(),
+        }
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[component(on_insert)]
+#[require(LevelComponent)]
+#[reflect(Component, Default)]
+pub struct MutationPoolSensorChunk {
+    /// Id of the chunk that will be mutated
+    pub chunk: ChunkId,
+    /// Candidates of which one is picked by weight, seeded by the run seed
+    pub pool: Vec<WeightedChunkMutation>,
+    /// Whether the sensor should be preserved or removed after the first mutation
+    pub preserve_after_mutation: bool,
+}
+
+impl MutationPoolSensorChunk {
+    fn on_insert<'a>(mut world: DeferredWorld<'a>, hook: HookContext) {
+        let Some(asset_server) = world.get_resource::<AssetServer>() else {
+            return;
+        };
+        let this: &Self = world.get(hook.entity).unwrap();
+        let handles = this.load_handles(asset_server);
+        world.commands().entity(hook.entity).insert(handles);
+    }
+
+    fn load_handles(&self, asset_server: &AssetServer) -> MutationPoolSensorChunkHandles {
+        MutationPoolSensorChunkHandles {
+            assets: self
+                .pool
+                .iter()
+                .map(|candidate| match &candidate.mutation {
+                    ChunkMutation::Swap(_) => None,
+                    ChunkMutation::ReplaceAsset(descriptor) => {
+                        Some(asset_server.load(ChunkDescriptorAsset::path_from_name(descriptor)))
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Reload the handles of pools edited in place, e.g. from the inspector, which doesn't run the
+/// insert hook
+fn reload_mutation_pool_handles(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    sensors: Query<(Entity, Ref<MutationPoolSensorChunk>), Changed<MutationPoolSensorChunk>>,
+) {
+    for (entity, sensor) in &sensors {
+        if sensor.is_added() {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(sensor.load_handles(&asset_server));
+    }
+}
+
+#[derive(Component)]
+/// Parallel to [`MutationPoolSensorChunk::pool`], holds the descriptor handle of every
+/// [`ChunkMutation::ReplaceAsset`] candidate
+pub struct MutationPoolSensorChunkHandles {
+    pub assets: Vec<Option<Handle<ChunkDescriptor>>>,
+}
+
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+/// Number of times a [`MutationPoolSensorChunk`] has been rolled
+pub struct MutationPoolRolls(pub u32);
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+pub struct TeleportSensorChunk {
+    /// Id of the chunk the player is teleported to, keeping their offset within the chunk
+    pub target: ChunkId,
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+pub struct DoorSensorChunk {
+    /// Id of the doors to operate, see [`DoorInteraction::id`]
+    pub door: u32,
+    pub action: DoorAction,
+    /// Whether the sensor should be preserved or removed after the first trigger
+    pub preserve_after_trigger: bool,
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+pub struct StorySensorChunk {
+    /// Applied to the [`StoryState`] when the player enters this chunk
+    pub effects: Vec<StoryEffect>,
+    /// The sensor is only triggered while all conditions hold
+    pub conditions: Vec<StoryCondition>,
+    /// Whether the sensor should be preserved or removed after the first trigger
+    pub preserve_after_trigger: bool,
+}
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+/// The player arriving by elevator is placed in this chunk instead of at the spawn marker
+pub struct ElevatorArrivalChunk;
+
+#[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(LevelComponent)]
+/// Changes the player's sanity while they are in this chunk, restoring it in safe rooms
+pub struct SanityZoneChunk {
+    /// Change of sanity per second, negative values drain it
+    pub change_per_sec: f32,
+}
+
+#[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default, Component)]
+#[component(on_add)]
+#[require(LevelComponent)]
+/// A cuboid door spawned by a [`ChunkMarker::Door`], sized by the scale of `transform`
+pub struct ChunkDoor {
+    pub transform: Transform,
+    pub color: Color,
+    pub door: DoorInteraction,
+}
+
+impl Default for ChunkDoor {
+    fn default() -> Self {
+        Self {
+            transform: Transform::from_xyz(0., 1.25, 0.).with_scale(Vec3::new(2., 2.5, 0.2)),
+            color: Color::srgb(0.3, 0.2, 0.15),
+            door: DoorInteraction::default(),
+        }
+    }
+}
+
+impl ChunkDoor {
+    fn on_add<'a>(mut world: DeferredWorld<'a>, hook: HookContext) {
+        if world.get_resource::<AssetServer>().is_none() {
+            return;
+        }
+        let this: Self = world.get(hook.entity).cloned().unwrap();
+        // Keep the state of doors restored from a saved scene
+        world.commands().entity(hook.entity).insert_if_new((
+            this.transform,
+            LevelComponent3d {
+                shape: LevelComponentShape::Cube { length: 1. },
+                color: this.color,
+                has_collider: true,
+            },
+            this.door,
+        ));
+    }
+}
+
 #[derive(Component, Debug, Default, Clone, Serialize, Deserialize, Reflect)]
 #[reflect(Default, Component)]
 #[component(on_add)]
@@ -141,7 +622,51 @@ pub struct ChunkMarkers(pub Vec<ChunkMarker>);
 #[reflect(Component)]
 pub struct SpawnMarker(pub Transform);
 
+#[cfg(feature = "dev_native")]
+#[derive(Component)]
+struct SpawnMarkerIndicator;
+
 impl ChunkMarkers {
+    /// Remove everything added by the markers of `chunk`, so that new markers can be inserted
+    pub fn clear(world: &mut World, chunk: Entity) {
+        let children: Vec<Entity> = world
+            .get::<Children>(chunk)
+            .map(|children| children.iter().collect())
+            .unwrap_or_default();
+        for child in children {
+            let child_ref = world.entity(child);
+            #[cfg(feature = "dev_native")]
+            let is_indicator = child_ref.contains::<SpawnMarkerIndicator>();
+            #[cfg(not(feature = "dev_native"))]
+            let is_indicator = false;
+            if child_ref.contains::<ChunkLight>()
+                || child_ref.contains::<ChunkDoor>()
+                || is_indicator
+            {
+                world.entity_mut(child).despawn();
+            }
+        }
+
+        world.entity_mut(chunk).remove::<(
+            ChunkMarkers,
+            SpawnMarker,
+            SwapSensorChunk,
+            ReplaceAssetSensorChunk,
+            ReplaceAssetSensorChunkHandle,
+            RevertAssetSensorChunk,
+            MoveChunkSensorChunk,
+            MutationPoolSensorChunk,
+            MutationPoolSensorChunkHandles,
+            MutationPoolRolls,
+            DormancySensorChunk,
+            TeleportSensorChunk,
+            DoorSensorChunk,
+            StorySensorChunk,
+            ElevatorArrivalChunk,
+            SanityZoneChunk,
+        )>();
+    }
+
     fn on_add(mut world: DeferredWorld<'_>, hook: HookContext) {
         let markers = world.get::<ChunkMarkers>(hook.entity).unwrap().0.clone();
 
@@ -162,6 +687,7 @@ impl ChunkMarkers {
                             .insert(SpawnMarker(t))
                             .with_child((
                                 Name::new("Player spawn indicator"),
+                                SpawnMarkerIndicator,
                                 Mesh3d(mesh),
                                 MeshMaterial3d(material),
                                 bevy::light::NotShadowCaster,
@@ -179,6 +705,39 @@ impl ChunkMarkers {
                 ChunkMarker::MoveChunkSensor(sensor) => {
                     world.commands().entity(hook.entity).insert(sensor);
                 }
+                ChunkMarker::MutationPoolSensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::DormancySensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::TeleportSensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::RevertAssetSensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::DoorSensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::StorySensor(sensor) => {
+                    world.commands().entity(hook.entity).insert(sensor);
+                }
+                ChunkMarker::SanityZone(zone) => {
+                    world.commands().entity(hook.entity).insert(zone);
+                }
+                ChunkMarker::ElevatorArrival => {
+                    world
+                        .commands()
+                        .entity(hook.entity)
+                        .insert(ElevatorArrivalChunk);
+                }
+                ChunkMarker::Door(door) => {
+                    world
+                        .commands()
+                        .entity(hook.entity)
+                        .with_child((Name::new("Door"), door));
+                }
                 ChunkMarker::Light(light) => {
                     world
                         .commands()
@@ -203,10 +762,6 @@ pub fn on_spawn_chunk(
     let Some(descriptor) = descriptors.get(&event.descriptor) else {
         return;
     };
-    let elements = descriptor
-        .elements
-        .iter()
-        .filter_map(|e| elements.get(&e.0));
 
     let transform = Transform::from_xyz(
         grid_position.x * CHUNK_SIZE,
@@ -238,16 +793,49 @@ pub fn on_spawn_chunk(
         chunk_cmds.insert(ChunkMarkers(event.components.clone()));
     }
 
+    if event.dormant {
+        chunk_cmds.insert(Dormant);
+    }
+
     let chunk_entity = chunk_cmds.id();
 
+    spawn_chunk_elements(&mut commands, chunk_entity, descriptor, &elements);
+}
+
+/// Spawn the elements of `descriptor` as children of `chunk`
+pub fn spawn_chunk_elements(
+    commands: &mut Commands,
+    chunk: Entity,
+    descriptor: &ChunkDescriptor,
+    elements: &Assets<ChunkElement>,
+) {
+    let elements = descriptor
+        .elements
+        .iter()
+        .filter_map(|e| elements.get(&e.0));
+
     for element in elements {
         let mut element_entity = commands.spawn((
             Name::new(element.name.clone()),
             element.transform,
             Visibility::Visible,
-            ChildOf(chunk_entity),
+            ChunkElementInstance,
+            ChildOf(chunk),
         ));
 
+        if !element.components.is_empty() {
+            let components = element.components.clone();
+            element_entity.queue(move |mut entity: EntityWorldMut| {
+                let registry = entity.resource::<AppTypeRegistry>().clone();
+                let registry = registry.read();
+                for component in &components {
+                    if let Err(error) = component.insert(&mut entity, &registry) {
+                        warn!("Failed to insert {}: {error}", component.type_path);
+                    }
+                }
+            });
+        }
+
         if let ChunkElementShape::Gltf { mesh_path, .. } = &element.shape {
             element_entity.insert(LevelComponentGltf {
                 path: mesh_path.clone(),
@@ -271,17 +859,8 @@ pub fn on_spawn_chunk(
     }
 }
 
-pub fn on_despawn_chunk(
-    event: On<DespawnChunk>,
-    mut commands: Commands,
-    chunks: Query<(Entity, &ChunkId), With<Chunk>>,
-) {
-    let ChunkId(chunk_id) = event.0;
-
-    for (entity, ChunkId(id)) in &chunks {
-        if chunk_id == *id {
-            commands.entity(entity).despawn();
-            return;
-        }
+pub fn on_despawn_chunk(event: On<DespawnChunk>, mut commands: Commands, index: Res<ChunkIndex>) {
+    if let Some(entity) = index.entity(event.0) {
+        commands.entity(entity).despawn();
     }
 }
@@ -3,9 +3,11 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use bevy::reflect::{TypeRegistry, serde::TypedReflectDeserializer};
 #[cfg(feature = "dev_native")]
 use bevy_inspector_egui::inspector_egui_impls::InspectorEguiImpl;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeSeed};
+use thiserror::Error;
 
 use crate::{
     asset_loader::{RonAsset, RonAssetLoader},
@@ -85,6 +87,51 @@ impl Default for DefaultWrap {
     }
 }
 
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+/// A reflected component inserted into a chunk element when it is spawned, so elements can be made
+/// interactive without editing their glTF files
+pub struct ElementComponent {
+    /// Type path of a registered component, e.g.
+    /// `feverdream_trap_core::interactions::DespawnInteraction`
+    pub type_path: String,
+    /// The component's value in RON, e.g. `()` for unit structs
+    pub value: String,
+}
+
+#[derive(Error, Debug)]
+pub enum ElementComponentError {
+    #[error("Type {0} is not registered")]
+    Unregistered(String),
+    #[error("Type {0} is not a reflected component")]
+    NotAComponent(String),
+    #[error("Component value is not valid RON: {0}")]
+    InvalidRon(#[from] ron::error::SpannedError),
+    #[error("Component value deserialization failed: {0}")]
+    DeserializationError(#[from] ron::Error),
+}
+
+impl ElementComponent {
+    /// Deserialize the component's value and insert it into `entity`
+    pub fn insert(
+        &self,
+        entity: &mut EntityWorldMut,
+        registry: &TypeRegistry,
+    ) -> Result<(), ElementComponentError> {
+        let registration = registry
+            .get_with_type_path(&self.type_path)
+            .ok_or_else(|| ElementComponentError::Unregistered(self.type_path.clone()))?;
+        let reflect_component = registration
+            .data::<ReflectComponent>()
+            .ok_or_else(|| ElementComponentError::NotAComponent(self.type_path.clone()))?;
+        let mut deserializer = ron::Deserializer::from_str(&self.value)?;
+        let value =
+            TypedReflectDeserializer::new(registration, registry).deserialize(&mut deserializer)?;
+        reflect_component.insert(entity, value.as_partial_reflect(), registry);
+        Ok(())
+    }
+}
+
 #[derive(Asset, TypePath, Debug, Serialize, Deserialize)]
 pub struct ChunkElementAsset {
     pub name: String,
@@ -93,6 +140,8 @@ pub struct ChunkElementAsset {
     pub color: Color,
     /// Has no effect on [`ChunkElementShapeAsset::Gltf`]
     pub has_collider: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<ElementComponent>,
 }
 
 #[derive(Asset, Reflect, Debug, Clone)]
@@ -103,6 +152,8 @@ pub struct ChunkElement {
     pub shape: ChunkElementShape,
     pub color: Color,
     pub has_collider: bool,
+    /// Components inserted into the element when it is spawned
+    pub components: Vec<ElementComponent>,
 }
 
 impl ChunkElement {
@@ -113,6 +164,7 @@ impl ChunkElement {
             shape: ChunkElementShape::Cube,
             color: Color::WHITE,
             has_collider: true,
+            components: Vec::new(),
         }
     }
 }
@@ -147,6 +199,7 @@ impl From<&ChunkElement> for ChunkElementAsset {
             },
             color: value.color,
             has_collider: value.has_collider,
+            components: value.components.clone(),
         }
     }
 }
@@ -171,6 +224,7 @@ impl RonAsset for ChunkElementAsset {
             shape,
             color: self.color,
             has_collider: self.has_collider,
+            components: self.components,
         }
     }
 }
@@ -335,9 +389,33 @@ pub enum ChunkMarker {
     /// Mark this chunk as a sensor chunk, that will cause one chunk to be replaced with a
     /// not-yet-loaded chunk asset when this one is entered by the player
     ReplaceAssetSensor(ReplaceAssetSensorChunk),
+    /// Mark this chunk as a sensor chunk, that will revert one chunk to a descriptor it had before
+    /// being replaced when this one is entered by the player
+    RevertAssetSensor(RevertAssetSensorChunk),
     /// Mark this chunk as a sensor chunk, that will move a given chunk to a given position
     /// when this one is entered by the player
     MoveChunkSensor(MoveChunkSensorChunk),
+    /// Mark this chunk as a sensor chunk, that will apply a mutation picked from a weighted pool
+    /// to a given chunk when this one is entered by the player
+    MutationPoolSensor(MutationPoolSensorChunk),
+    /// Mark this chunk as a sensor chunk, that will activate and deactivate
+    /// [`ChunkEntryAsset::dormant`] chunks when this one is entered by the player
+    DormancySensor(DormancySensorChunk),
+    /// Mark this chunk as a sensor chunk, that will seamlessly teleport the player to another chunk
+    /// when they cross into this one
+    TeleportSensor(TeleportSensorChunk),
+    /// Spawn a door in this chunk
+    Door(ChunkDoor),
+    /// Mark this chunk as a sensor chunk, that will operate doors when this one is entered by the
+    /// player
+    DoorSensor(DoorSensorChunk),
+    /// Mark this chunk as a sensor chunk, that will update the story state when this one is
+    /// entered by the player
+    StorySensor(StorySensorChunk),
+    /// The player arriving in this level by elevator is placed in this chunk
+    ElevatorArrival,
+    /// Drain or restore the player's sanity while they are in this chunk
+    SanityZone(SanityZoneChunk),
 }
 
 impl Default for ChunkMarker {
@@ -352,6 +430,9 @@ pub struct ChunkEntryAsset {
     pub descriptor: String,
     #[serde(default)]
     pub components: Vec<ChunkMarker>,
+    /// Dormant chunks are spawned hidden and without collisions until they are activated
+    #[serde(default)]
+    pub dormant: bool,
 }
 
 #[derive(Asset, TypePath, Debug, Serialize, Deserialize)]
@@ -364,6 +445,7 @@ pub struct ChunkEntry {
     pub grid_pos: (i32, i32),
     pub descriptor: Handle<ChunkDescriptor>,
     pub components: Vec<ChunkMarker>,
+    pub dormant: bool,
 }
 
 #[derive(Asset, Reflect, Debug)]
@@ -384,6 +466,7 @@ impl From<(&ChunkLayout, &Assets<ChunkDescriptor>)> for ChunkLayoutAsset {
                         grid_pos: entry.grid_pos,
                         descriptor: descriptors.get(&entry.descriptor).unwrap().name.clone(),
                         components: entry.components.clone(),
+                        dormant: entry.dormant,
                     },
                 )
             })
@@ -415,6 +498,7 @@ impl RonAsset for ChunkLayoutAsset {
                         grid_pos: entry.grid_pos,
                         descriptor,
                         components: entry.components,
+                        dormant: entry.dormant,
                     },
                 )
             })
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::prelude::*;
 
 #[derive(Debug, Default, Component, Reflect)]
@@ -20,10 +22,264 @@ pub struct SwapChunksInteraction {
 #[reflect(Component)]
 pub struct PlaySoundEffectInteraction(pub String);
 
+/// Default [`InteractionDistance`]
+pub const INTERACTION_DISTANCE: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+/// How far away from the camera an interactable can be focused
+pub struct InteractionDistance(pub f32);
+
+impl Default for InteractionDistance {
+    fn default() -> Self {
+        Self(INTERACTION_DISTANCE)
+    }
+}
+
+#[derive(Debug, Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+/// Prompt shown while an interactable is in focus, e.g. "Pick up fuse"
+pub struct InteractionPrompt {
+    pub verb: String,
+    pub label: String,
+    /// Shown instead when the interaction's requirements aren't met, a generic message is used if
+    /// empty
+    pub disabled: String,
+}
+
+impl Default for InteractionPrompt {
+    fn default() -> Self {
+        Self::new("Interact", "")
+    }
+}
+
+impl InteractionPrompt {
+    pub fn new(verb: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            verb: verb.into(),
+            label: label.into(),
+            disabled: String::new(),
+        }
+    }
+
+    pub fn with_disabled(mut self, disabled: impl Into<String>) -> Self {
+        self.disabled = disabled.into();
+        self
+    }
+
+    pub fn text(&self) -> String {
+        format!("{} {}", self.verb, self.label).trim().to_owned()
+    }
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+/// The interaction is unavailable for now, its [`InteractionPrompt`] is shown as disabled
+pub struct InteractionLocked;
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+/// The interact input has to be held for `duration_secs` before the interaction fires
+pub struct HoldToInteract {
+    pub duration_secs: f32,
+}
+
+#[derive(Debug, Default, Clone, Reflect)]
+#[reflect(Default)]
+pub struct InteractionStage {
+    pub prompt: InteractionPrompt,
+    pub requires: Option<RequiresItem>,
+    /// How long the interact input has to be held to complete this stage, instant if zero
+    pub hold_secs: f32,
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+/// Stages completed one interaction at a time before the entity's other interactions fire, e.g.
+/// insert a fuse, then pull a lever
+pub struct InteractionStages {
+    pub stages: Vec<InteractionStage>,
+    /// Index of the current stage, all stages are completed once it reaches their count
+    pub current: usize,
+}
+
+impl InteractionStages {
+    pub fn current(&self) -> Option<&InteractionStage> {
+        self.stages.get(self.current)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Hash, PartialEq)]
+pub enum Item {
+    #[default]
+    Fuse,
+}
+
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+/// Adds `count` of `item` to the player's inventory
+pub struct PickupInteraction {
+    pub item: Item,
+    pub count: u32,
+}
+
+impl Default for PickupInteraction {
+    fn default() -> Self {
+        Self {
+            item: Item::default(),
+            count: 1,
+        }
+    }
+}
+
+#[derive(Debug, Component, Clone, Reflect)]
+#[reflect(Component, Default)]
+/// The interaction is only carried out if the player holds `count` of `item`
+pub struct RequiresItem {
+    pub item: Item,
+    pub count: u32,
+    /// Whether the required items are removed from the inventory on interaction
+    pub consume: bool,
+}
+
+impl Default for RequiresItem {
+    fn default() -> Self {
+        Self {
+            item: Item::default(),
+            count: 1,
+            consume: false,
+        }
+    }
+}
+
 #[derive(Debug, Default, Component, Reflect)]
 #[reflect(Component)]
+#[require(
+    PickupInteraction { item: Item::Fuse, count: 1 },
+    InteractionPrompt::new("Pick up", "fuse")
+)]
+/// Pickup of a single [`Item::Fuse`], used by the fuse glTF files
 pub struct PickupFuseInteraction;
 
 #[derive(Debug, Default, Component, Reflect)]
 #[reflect(Component)]
+#[require(
+    RequiresItem { item: Item::Fuse, count: 1, consume: true },
+    InteractionPrompt::new("Call", "elevator").with_disabled("The elevator has no power")
+)]
 pub struct ElevatorInteraction;
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+/// Id of the doors closed before an [`ElevatorInteraction`]'s ride and opened on arrival in the
+/// next level
+pub struct ElevatorDoors(pub u32);
+
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(RigidBody::Dynamic, InteractionPrompt::new("Pick up", ""))]
+/// A dynamic prop the player can carry in front of the camera, drop, throw and place into
+/// [`PropSocket`]s
+pub struct CarryInteraction {
+    /// Matched against [`PropSocket::key`]
+    pub key: String,
+    /// Distance in front of the camera the prop is held at
+    pub hold_distance: f32,
+    pub throw_speed: f32,
+}
+
+impl Default for CarryInteraction {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            hold_distance: 1.5,
+            throw_speed: 12.,
+        }
+    }
+}
+
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component, Default)]
+/// Carried props with a matching key dropped within `radius` are placed into the socket, which
+/// then fires its own interactions
+pub struct PropSocket {
+    pub key: String,
+    pub radius: f32,
+    /// Whether the prop is despawned or left in the socket
+    pub consume: bool,
+}
+
+impl Default for PropSocket {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            radius: 1.,
+            consume: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Reflect)]
+#[reflect(Default)]
+pub struct NotePage {
+    pub text: String,
+    /// Path of an image shown below the text, empty for none
+    pub image: String,
+}
+
+#[derive(Debug, Default, Clone, Reflect)]
+#[reflect(Default)]
+pub struct Note {
+    /// Also identifies the note in the journal
+    pub title: String,
+    pub pages: Vec<NotePage>,
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(InteractionPrompt::new("Read", "note"))]
+/// Opens the note and records it in the journal
+pub struct ReadNoteInteraction(pub Note);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub enum DoorAction {
+    #[default]
+    Toggle,
+    Open,
+    Close,
+    Unlock,
+    Lock,
+}
+
+#[derive(Debug, Component, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component, Default)]
+#[require(InteractionPrompt::new("Use", "door").with_disabled("It's locked"))]
+pub struct DoorInteraction {
+    /// Doors sharing an id are operated together by [`DoorAction`]s
+    pub id: u32,
+    /// Transform of the open door relative to the closed one, in the parent's space
+    pub open_offset: Transform,
+    pub duration_secs: f32,
+    /// Locked doors have to be unlocked by a [`DoorAction::Unlock`] before they can be opened
+    pub locked: bool,
+    /// Sound effects, empty paths are skipped
+    pub open_sound: String,
+    pub close_sound: String,
+    pub locked_sound: String,
+}
+
+impl Default for DoorInteraction {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            open_offset: Transform::from_xyz(0., 2.5, 0.),
+            duration_secs: 1.,
+            locked: false,
+            open_sound: String::new(),
+            close_sound: String::new(),
+            locked_sound: String::new(),
+        }
+    }
+}
@@ -141,7 +141,10 @@ impl LevelComponent3d {
         let mut cmds = world.commands();
         let mut entity_cmds = cmds.entity(hook.entity);
 
-        entity_cmds.insert((RigidBody::Static, Mesh3d(mesh), MeshMaterial3d(material)));
+        // Moving components like doors bring their own body
+        entity_cmds
+            .insert((Mesh3d(mesh), MeshMaterial3d(material)))
+            .insert_if_new(RigidBody::Static);
 
         if let Some(collider) = collider_maybe {
             entity_cmds.insert(collider);
@@ -174,6 +177,7 @@ pub fn spawn_level_from_layout(
             grid_position: Vec2::new(entry.grid_pos.0 as f32, entry.grid_pos.1 as f32),
             descriptor: entry.descriptor.clone(),
             components: entry.components.clone(),
+            dormant: entry.dormant,
         });
     }
 }
@@ -1,4 +1,4 @@
-mod asset_loader;
+pub mod asset_loader;
 mod asset_tracking;
 pub mod chunk;
 pub mod chunk_assets;
@@ -6,6 +6,7 @@ pub mod interactions;
 pub mod level;
 pub mod physics;
 pub mod prelude;
+pub mod story;
 mod theme;
 pub(crate) mod utils;
 
@@ -17,6 +18,7 @@ pub fn utility_plugin<S: States>(app: &mut App, state: Option<S>) {
         asset_tracking::plugin,
         chunk_assets::plugin,
         chunk::plugin,
+        story::plugin,
         utils::audio::plugin,
     ));
 
@@ -6,6 +6,7 @@ pub use crate::chunk_assets::*;
 pub use crate::interactions::*;
 pub use crate::level::*;
 pub use crate::physics::*;
+pub use crate::story::*;
 pub use crate::theme::widget;
 pub use crate::utils::audio::{music, sound_effect};
 pub use crate::utils::*;
@@ -18,7 +19,11 @@ pub use bevy::prelude::*;
 #[cfg(feature = "dev_native")]
 pub use bevy_egui::PrimaryEguiContext;
 #[cfg(feature = "dev_native")]
-pub use bevy_inspector_egui::{bevy_egui::EguiPlugin, prelude::*, quick::WorldInspectorPlugin};
+pub use bevy_inspector_egui::{
+    bevy_egui::{EguiContexts, EguiPlugin, EguiPrimaryContextPass, egui},
+    prelude::*,
+    quick::WorldInspectorPlugin,
+};
 
 /// Common std imports
 pub use std::f32::consts::{PI, TAU};
@@ -0,0 +1,112 @@
+//! Named story flags and variables, e.g. whether the player has seen the red cube or how many pills
+//! were taken.
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<StoryState>();
+}
+
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+/// Story flags and variables, saved with the game scene
+pub struct StoryState {
+    flags: HashMap<String, bool>,
+    variables: HashMap<String, i32>,
+}
+
+impl StoryState {
+    /// Unset flags are false
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.get(name).copied().unwrap_or_default()
+    }
+
+    pub fn set_flag(&mut self, name: impl Into<String>, value: bool) {
+        self.flags.insert(name.into(), value);
+    }
+
+    /// Unset variables are zero
+    pub fn variable(&self, name: &str) -> i32 {
+        self.variables.get(name).copied().unwrap_or_default()
+    }
+
+    pub fn set_variable(&mut self, name: impl Into<String>, value: i32) {
+        self.variables.insert(name.into(), value);
+    }
+
+    pub fn increment(&mut self, name: impl Into<String>, by: i32) {
+        *self.variables.entry(name.into()).or_default() += by;
+    }
+
+    pub fn apply(&mut self, effect: &StoryEffect) {
+        match effect {
+            StoryEffect::SetFlag { name, value } => self.set_flag(name, *value),
+            StoryEffect::SetVariable { name, value } => self.set_variable(name, *value),
+            StoryEffect::Increment { name, by } => self.increment(name, *by),
+        }
+    }
+
+    pub fn check(&self, condition: &StoryCondition) -> bool {
+        match condition {
+            StoryCondition::Flag { name, value } => self.flag(name) == *value,
+            StoryCondition::Equals { name, value } => self.variable(name) == *value,
+            StoryCondition::AtLeast { name, value } => self.variable(name) >= *value,
+            StoryCondition::Below { name, value } => self.variable(name) < *value,
+        }
+    }
+
+    pub fn check_all(&self, conditions: &[StoryCondition]) -> bool {
+        conditions.iter().all(|condition| self.check(condition))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub enum StoryEffect {
+    SetFlag { name: String, value: bool },
+    SetVariable { name: String, value: i32 },
+    Increment { name: String, by: i32 },
+}
+
+impl Default for StoryEffect {
+    fn default() -> Self {
+        Self::SetFlag {
+            name: String::new(),
+            value: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Default)]
+pub enum StoryCondition {
+    Flag { name: String, value: bool },
+    Equals { name: String, value: i32 },
+    AtLeast { name: String, value: i32 },
+    Below { name: String, value: i32 },
+}
+
+impl Default for StoryCondition {
+    fn default() -> Self {
+        Self::Flag {
+            name: String::new(),
+            value: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+/// Applies `effects` to the [`StoryState`] on interaction
+pub struct StoryInteraction {
+    pub effects: Vec<StoryEffect>,
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+/// The entity's interactions only fire while all `conditions` hold
+pub struct StoryConditions {
+    pub conditions: Vec<StoryCondition>,
+}
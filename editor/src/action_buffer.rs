@@ -14,6 +14,14 @@ pub(super) fn plugin(app: &mut App) {
 #[derive(Resource, Default, Debug)]
 pub struct SelectedLevel(pub GameLevel);
 
+/// Grid position, descriptor, markers and dormancy of a layout chunk
+pub type LayoutBufferEntry = (
+    (String, String),
+    Handle<ChunkDescriptor>,
+    Vec<ChunkMarker>,
+    bool,
+);
+
 #[derive(Default, Resource, Reflect, Debug)]
 #[reflect(Resource)]
 pub struct EguiActionBuffer {
@@ -23,12 +31,12 @@ pub struct EguiActionBuffer {
     pub new_layout_pos: (String, String),
     /// Count the hashmap inserts to prevent egui salt conflicts
     pub layout_push_counter: usize,
-    pub layout_buffer: BTreeMap<u32, ((String, String), Handle<ChunkDescriptor>, Vec<ChunkMarker>)>,
+    pub layout_buffer: BTreeMap<u32, LayoutBufferEntry>,
 }
 
 fn layout_buffer_from_chunks(
     chunks: &BTreeMap<u32, ChunkEntry>,
-) -> BTreeMap<u32, ((String, String), Handle<ChunkDescriptor>, Vec<ChunkMarker>)> {
+) -> BTreeMap<u32, LayoutBufferEntry> {
     chunks
         .iter()
         .map(|(id, entry)| {
@@ -38,6 +46,7 @@ fn layout_buffer_from_chunks(
                     (entry.grid_pos.0.to_string(), entry.grid_pos.1.to_string()),
                     entry.descriptor.clone(),
                     entry.components.clone(),
+                    entry.dormant,
                 ),
             )
         })
@@ -71,7 +80,7 @@ fn sync_layout_buffer(
 ) {
     let layout_buffer = buffer.layout_buffer.iter().fold(
         BTreeMap::new(),
-        |mut acc, (id, ((x, z), handle, markers))| {
+        |mut acc, (id, ((x, z), handle, markers, dormant))| {
             let Ok(x) = x.parse() else { return acc };
             let Ok(z) = z.parse() else { return acc };
             acc.insert(
@@ -80,6 +89,7 @@ fn sync_layout_buffer(
                     grid_pos: (x, z),
                     descriptor: handle.clone(),
                     components: markers.clone(),
+                    dormant: *dormant,
                 },
             );
             acc
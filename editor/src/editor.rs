@@ -14,7 +14,7 @@ use egui_dock::{DockArea, DockState, NodeIndex, Style};
 use std::path::PathBuf;
 
 use crate::{
-    action_buffer::{EguiActionBuffer, SelectedLevel, reload_layout_buffer},
+    action_buffer::{EguiActionBuffer, LayoutBufferEntry, SelectedLevel, reload_layout_buffer},
     prelude::*,
     preview::EditorPreview,
 };
@@ -350,11 +350,10 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                             .layout_buffer
                             .clone();
                         let mut delete_index = None;
-                        let mut clone_entry: Option<(
-                            u32,
-                            ((String, String), Handle<ChunkDescriptor>, Vec<ChunkMarker>),
-                        )> = None;
-                        for (chunk_id, ((x, y), descriptor, components)) in layout.iter_mut() {
+                        let mut clone_entry: Option<(u32, LayoutBufferEntry)> = None;
+                        for (chunk_id, ((x, y), descriptor, components, dormant)) in
+                            layout.iter_mut()
+                        {
                             ui.horizontal(|ui| {
                                 ui.vertical(|ui| {
                                     ui.horizontal(|ui| {
@@ -375,6 +374,8 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                                             egui::TextEdit::singleline(y),
                                         );
 
+                                        ui.checkbox(dormant, "Dormant");
+
                                         if ui.button("Delete chunk").clicked() {
                                             delete_index = Some(*chunk_id);
                                         }
@@ -385,6 +386,7 @@ impl egui_dock::TabViewer for TabViewer<'_> {
                                                     (x.clone(), y.clone()),
                                                     descriptor.clone(),
                                                     components.clone(),
+                                                    *dormant,
                                                 ),
                                             ));
                                         }
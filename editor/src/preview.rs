@@ -83,6 +83,7 @@ fn update_descriptor_preview(
                 grid_position: Vec2::ZERO,
                 descriptor: descriptor_handle.clone(),
                 components: vec![],
+                dormant: false,
             });
         }
         EditorPreview::Layout => commands.run_system_cached(spawn_level_from_layout),
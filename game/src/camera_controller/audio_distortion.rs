@@ -0,0 +1,218 @@
+//! Status effects distorting the [`Music`] and [`SoundEffect`] audio, see [`AudioInfluences`]
+
+use bevy::audio::{Pitch, Volume};
+
+use crate::{
+    camera_controller::{CameraStatusEffects, status_effects::CameraEffect},
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<AudioInfluences>()
+        .add_systems(
+            Update,
+            (
+                distort_music,
+                duck_audio,
+                (beat_heart, ring_tinnitus).in_set(PausableSystems),
+            )
+                .run_if(in_state(Screen::Gameplay)),
+        )
+        .add_systems(OnExit(Screen::Gameplay), restore_audio)
+        .add_systems(OnEnter(Pause(true)), pause_tinnitus)
+        .add_systems(OnExit(Pause(true)), resume_tinnitus);
+}
+
+/// Music speed lost at full slowdown, lowering its pitch as well
+const MAX_MUSIC_SLOWDOWN: f32 = 0.4;
+/// Volume lost at full ducking
+const MAX_DUCKING: f32 = 0.8;
+const HEARTBEAT_FREQUENCY: f32 = 55.;
+const HEARTBEAT_PULSE_SECS: f32 = 0.15;
+/// Heartbeats per second at the start, rising by one per unit of heartbeat amount
+const HEARTBEAT_RATE: f32 = 1.;
+const TINNITUS_FREQUENCY: f32 = 6000.;
+/// Tinnitus volume at full amount
+const TINNITUS_VOLUME: f32 = 0.15;
+/// Amount below which the heartbeat and tinnitus layers are silent
+const LAYER_THRESHOLD: f32 = 0.05;
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioParameter {
+    /// Slow down the music, lowering its pitch
+    MusicSlowdown,
+    /// Lower the volume of the music and sound effects
+    Ducking,
+    /// Fade in a heartbeat that grows faster
+    Heartbeat,
+    /// Fade in a high-pitched ringing
+    Tinnitus,
+}
+
+/// `effect` drives `parameter` by its intensity times `scale`
+#[derive(Reflect, Debug, Clone)]
+pub struct AudioInfluence {
+    pub effect: CameraEffect,
+    pub parameter: AudioParameter,
+    pub scale: f32,
+}
+
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+/// How the [`CameraStatusEffects`] distort the audio
+pub struct AudioInfluences(pub Vec<AudioInfluence>);
+
+impl Default for AudioInfluences {
+    fn default() -> Self {
+        Self(vec![
+            AudioInfluence {
+                effect: CameraEffect::ChromaticAbberation,
+                parameter: AudioParameter::MusicSlowdown,
+                scale: 0.5,
+            },
+            AudioInfluence {
+                effect: CameraEffect::ChromaticAbberation,
+                parameter: AudioParameter::Heartbeat,
+                scale: 0.5,
+            },
+            AudioInfluence {
+                effect: CameraEffect::ScreenDarken,
+                parameter: AudioParameter::Ducking,
+                scale: 0.3,
+            },
+            AudioInfluence {
+                effect: CameraEffect::FilmGrain,
+                parameter: AudioParameter::Tinnitus,
+                scale: 1.,
+            },
+        ])
+    }
+}
+
+impl AudioInfluences {
+    /// Sum of the scaled intensities driving `parameter`, between 0 and 1
+    fn amount(&self, effects: &CameraStatusEffects, parameter: AudioParameter) -> f32 {
+        self.0
+            .iter()
+            .filter(|influence| influence.parameter == parameter)
+            .filter_map(|influence| {
+                let status = effects.effects.get(&influence.effect)?;
+                Some(status.intensity * influence.scale)
+            })
+            .sum::<f32>()
+            .clamp(0., 1.)
+    }
+}
+
+#[derive(Debug, Component)]
+struct TinnitusLayer;
+
+fn distort_music(
+    effects: Res<CameraStatusEffects>,
+    influences: Res<AudioInfluences>,
+    mut music: Query<&mut AudioSink, With<Music>>,
+) {
+    let slowdown = influences.amount(&effects, AudioParameter::MusicSlowdown);
+    for mut sink in &mut music {
+        sink.set_speed(1. - slowdown * MAX_MUSIC_SLOWDOWN);
+    }
+}
+
+/// Applies on top of [`GlobalVolume`], leaving fading audio alone
+fn duck_audio(
+    global_volume: Res<GlobalVolume>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<AudioInfluences>,
+    mut audio: Query<
+        (&PlaybackSettings, &mut AudioSink),
+        (Or<(With<Music>, With<SoundEffect>)>, Without<Fade>),
+    >,
+) {
+    let ducking = influences.amount(&effects, AudioParameter::Ducking);
+    let duck = Volume::Linear(1. - ducking * MAX_DUCKING);
+    for (playback, mut sink) in &mut audio {
+        sink.set_volume(global_volume.volume * playback.volume * duck);
+    }
+}
+
+fn beat_heart(
+    mut commands: Commands,
+    time: Res<Time>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<AudioInfluences>,
+    mut pitches: ResMut<Assets<Pitch>>,
+    mut since_beat: Local<f32>,
+) {
+    let heartbeat = influences.amount(&effects, AudioParameter::Heartbeat);
+    *since_beat += time.delta_secs();
+    if heartbeat < LAYER_THRESHOLD || *since_beat < 1. / (HEARTBEAT_RATE + heartbeat) {
+        return;
+    }
+    *since_beat = 0.;
+    commands.spawn((
+        Name::new("Heartbeat"),
+        AudioPlayer(pitches.add(Pitch::new(
+            HEARTBEAT_FREQUENCY,
+            Duration::from_secs_f32(HEARTBEAT_PULSE_SECS),
+        ))),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(heartbeat)),
+        DespawnOnExit(Screen::Gameplay),
+    ));
+}
+
+fn ring_tinnitus(
+    mut commands: Commands,
+    global_volume: Res<GlobalVolume>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<AudioInfluences>,
+    mut pitches: ResMut<Assets<Pitch>>,
+    mut layer: Query<(Entity, Option<&mut AudioSink>), With<TinnitusLayer>>,
+) {
+    let tinnitus = influences.amount(&effects, AudioParameter::Tinnitus);
+    let volume = Volume::Linear(tinnitus * TINNITUS_VOLUME);
+    match layer.single_mut() {
+        Ok((entity, _)) if tinnitus < LAYER_THRESHOLD => commands.entity(entity).despawn(),
+        Ok((_, Some(mut sink))) => sink.set_volume(global_volume.volume * volume),
+        Ok((_, None)) => {}
+        Err(_) if tinnitus >= LAYER_THRESHOLD => {
+            commands.spawn((
+                Name::new("Tinnitus"),
+                TinnitusLayer,
+                AudioPlayer(pitches.add(Pitch::new(TINNITUS_FREQUENCY, Duration::from_secs(1)))),
+                PlaybackSettings::LOOP.with_volume(volume),
+                DespawnOnExit(Screen::Gameplay),
+            ));
+        }
+        Err(_) => {}
+    }
+}
+
+/// Undo the slowdown and ducking, so they don't carry over to the menus
+fn restore_audio(
+    global_volume: Res<GlobalVolume>,
+    mut audio: Query<
+        (&PlaybackSettings, &mut AudioSink, Has<Music>, Has<Fade>),
+        Or<(With<Music>, With<SoundEffect>)>,
+    >,
+) {
+    for (playback, mut sink, music, fading) in &mut audio {
+        if music {
+            sink.set_speed(1.);
+        }
+        if !fading {
+            sink.set_volume(global_volume.volume * playback.volume);
+        }
+    }
+}
+
+fn pause_tinnitus(layer: Query<&AudioSink, With<TinnitusLayer>>) {
+    for sink in &layer {
+        sink.pause();
+    }
+}
+
+fn resume_tinnitus(layer: Query<&AudioSink, With<TinnitusLayer>>) {
+    for sink in &layer {
+        sink.play();
+    }
+}
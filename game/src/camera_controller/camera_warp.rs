@@ -0,0 +1,180 @@
+//! Status effects applied to the camera's projection and transform
+
+use bevy::transform::TransformSystems;
+
+use crate::{
+    camera_controller::{CameraMarker, CameraStatusEffects, status_effects::CameraEffect},
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(First, restore_camera_sway)
+        .add_systems(
+            Update,
+            advance_camera_warp
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(PausableSystems),
+        )
+        .add_systems(
+            PostUpdate,
+            (breathe_fov, sway_camera)
+                .run_if(in_state(Screen::Gameplay))
+                .before(TransformSystems::Propagate),
+        );
+}
+
+/// Field of view change at an intensity of 1, in radians
+const FOV_BREATHING_AMPLITUDE: f32 = 0.15;
+/// Breaths per second
+const FOV_BREATHING_FREQUENCY: f32 = 0.25;
+/// Camera roll at an intensity of 1, in radians
+const ROLL_AMPLITUDE: f32 = 0.08;
+/// Camera offset at an intensity of 1
+const SWAY_AMPLITUDE: f32 = 0.05;
+/// Intensity at which the warping stops growing
+const MAX_WARP_INTENSITY: f32 = 4.;
+
+/// Shakes the camera while present, on top of the sway
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct CameraShake {
+    /// Maximum camera offset
+    pub amplitude: f32,
+}
+
+/// Warp applied to the camera, removed again at the start of the next frame so the warp never
+/// accumulates, whether or not the camera controller rewrites the transform
+#[derive(Component, Debug, Default)]
+pub(super) struct CameraWarp {
+    /// Seconds of unpaused gameplay driving the warp
+    secs: f32,
+    /// Field of view without breathing, taken from the projection on the first warp
+    base_fov: Option<f32>,
+    offset: Vec3,
+    roll: f32,
+}
+
+impl CameraWarp {
+    fn apply_sway(&mut self, transform: &mut Transform, offset: Vec3, roll: f32) {
+        transform.translation += transform.rotation * offset;
+        transform.rotate_local_z(roll);
+        self.offset = offset;
+        self.roll = roll;
+    }
+
+    fn restore_sway(&mut self, transform: &mut Transform) {
+        transform.rotate_local_z(-self.roll);
+        transform.translation -= transform.rotation * self.offset;
+        self.offset = Vec3::ZERO;
+        self.roll = 0.;
+    }
+}
+
+fn warp_intensity(effects: &CameraStatusEffects, effect: CameraEffect) -> f32 {
+    effects.intensity(effect).clamp(0., MAX_WARP_INTENSITY)
+}
+
+/// Field of view offset after `secs` seconds of breathing at `intensity`
+fn fov_offset(secs: f32, intensity: f32) -> f32 {
+    (secs * FOV_BREATHING_FREQUENCY * TAU).sin() * FOV_BREATHING_AMPLITUDE * intensity
+}
+
+/// Camera offset and roll after `secs` seconds of swaying at `intensity`
+fn sway(secs: f32, intensity: f32) -> (Vec3, f32) {
+    let offset =
+        Vec3::new((secs * 0.7).sin(), (secs * 1.3).sin() * 0.5, 0.) * SWAY_AMPLITUDE * intensity;
+    let roll = (secs * 0.5).sin() * ROLL_AMPLITUDE * intensity;
+    (offset, roll)
+}
+
+/// Camera offset after `secs` seconds of shaking, jittering fast enough to look random
+fn shake(secs: f32, amplitude: f32) -> Vec3 {
+    Vec3::new((secs * 37.).sin(), (secs * 53.).sin(), (secs * 41.).sin()) * amplitude
+}
+
+fn advance_camera_warp(time: Res<Time>, mut warp: Single<&mut CameraWarp>) {
+    warp.secs += time.delta_secs();
+}
+
+fn restore_camera_sway(mut camera: Query<(&mut Transform, &mut CameraWarp)>) {
+    for (mut transform, mut warp) in &mut camera {
+        warp.restore_sway(&mut transform);
+    }
+}
+
+fn breathe_fov(
+    effects: Res<CameraStatusEffects>,
+    camera: Single<(&mut Projection, &mut CameraWarp), With<CameraMarker>>,
+) {
+    let (mut projection, mut warp) = camera.into_inner();
+    let Projection::Perspective(perspective) = projection.as_mut() else {
+        return;
+    };
+    let base_fov = *warp.base_fov.get_or_insert(perspective.fov);
+    let intensity = warp_intensity(&effects, CameraEffect::FovBreathing);
+    perspective.fov = base_fov + fov_offset(warp.secs, intensity);
+}
+
+fn sway_camera(
+    effects: Res<CameraStatusEffects>,
+    camera_shake: Option<Res<CameraShake>>,
+    camera: Single<(&mut Transform, &mut CameraWarp), With<CameraMarker>>,
+) {
+    let (mut transform, mut warp) = camera.into_inner();
+    let intensity = warp_intensity(&effects, CameraEffect::Sway);
+    let (mut offset, roll) = sway(warp.secs, intensity);
+    if let Some(camera_shake) = camera_shake {
+        offset += shake(warp.secs, camera_shake.amplitude);
+    }
+    warp.apply_sway(&mut transform, offset, roll);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_warp_without_intensity() {
+        for secs in [0., 0.5, 3., 100.] {
+            assert_eq!(fov_offset(secs, 0.), 0.);
+            assert_eq!(sway(secs, 0.), (Vec3::ZERO, 0.));
+            assert_eq!(shake(secs, 0.), Vec3::ZERO);
+        }
+    }
+
+    #[test]
+    fn shake_stays_within_amplitude() {
+        for step in 0..100 {
+            let offset = shake(step as f32 * 0.05, 0.03);
+            assert!(offset.abs().max_element() <= 0.03);
+        }
+    }
+
+    #[test]
+    fn warp_scales_with_intensity() {
+        let secs = 1.3;
+        assert!((fov_offset(secs, 2.) - 2. * fov_offset(secs, 1.)).abs() < 1e-6);
+        let ((offset_1, roll_1), (offset_2, roll_2)) = (sway(secs, 1.), sway(secs, 2.));
+        assert!(offset_2.abs_diff_eq(offset_1 * 2., 1e-6));
+        assert!((roll_2 - 2. * roll_1).abs() < 1e-6);
+        assert!(fov_offset(secs, 1.).abs() <= FOV_BREATHING_AMPLITUDE);
+    }
+
+    #[test]
+    fn sway_does_not_accumulate() {
+        let base = Transform::from_xyz(1., 2., 3.).looking_at(Vec3::ZERO, Vec3::Y);
+        let mut transform = base;
+        let mut warp = CameraWarp::default();
+        let (offset, roll) = sway(1., 1.);
+
+        warp.apply_sway(&mut transform, offset, roll);
+        let swayed = transform;
+        for _ in 0..10 {
+            warp.restore_sway(&mut transform);
+            assert!(transform.translation.abs_diff_eq(base.translation, 1e-5));
+            assert!(transform.rotation.abs_diff_eq(base.rotation, 1e-5));
+            warp.apply_sway(&mut transform, offset, roll);
+        }
+        assert!(transform.translation.abs_diff_eq(swayed.translation, 1e-5));
+        assert!(transform.rotation.abs_diff_eq(swayed.rotation, 1e-5));
+    }
+}
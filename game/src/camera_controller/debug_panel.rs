@@ -0,0 +1,131 @@
+//! Egui panel to watch and tweak the [`CameraStatusEffects`], opened from the dev tools
+
+use std::collections::VecDeque;
+
+use bevy::asset::LoadedFolder;
+
+use crate::{
+    camera_controller::{
+        CameraStatusEffects,
+        drugs::{Drug, DrugAsset},
+        start_level_status_effects,
+        status_effects::CameraEffect,
+    },
+    character_controller::Sanity,
+    prelude::*,
+};
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<StatusEffectHistory>().add_systems(
+        Update,
+        record_status_effect_history.run_if(resource_exists::<CameraStatusEffects>),
+    );
+}
+
+/// Intensity samples kept per effect
+const HISTORY_LEN: usize = 300;
+const GRAPH_SIZE: egui::Vec2 = egui::vec2(240., 40.);
+
+#[derive(Resource, Default)]
+struct StatusEffectHistory(HashMap<CameraEffect, VecDeque<f32>>);
+
+fn record_status_effect_history(
+    effects: Res<CameraStatusEffects>,
+    mut history: ResMut<StatusEffectHistory>,
+) {
+    for (effect, status) in &effects.effects {
+        let samples = history.0.entry(*effect).or_default();
+        if samples.len() == HISTORY_LEN {
+            samples.pop_front();
+        }
+        samples.push_back(status.intensity);
+    }
+}
+
+pub(crate) fn status_effects_panel(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut effects: ResMut<CameraStatusEffects>,
+    mut sanity: ResMut<Sanity>,
+    history: Res<StatusEffectHistory>,
+    asset_server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    drugs: Res<Assets<Drug>>,
+    mut drug_folder: Local<Option<Handle<LoadedFolder>>>,
+) -> Result {
+    let drug_folder = drug_folder.get_or_insert_with(|| asset_server.load_folder(DrugAsset::PATH));
+    let drug_handles: Vec<Handle<Drug>> = folders.get(&*drug_folder).map_or(vec![], |folder| {
+        folder
+            .handles
+            .iter()
+            .filter_map(|handle| handle.clone().try_typed().ok())
+            .collect()
+    });
+
+    let mut camera_effects: Vec<CameraEffect> = effects.effects.keys().copied().collect();
+    camera_effects.sort_by_key(|effect| format!("{effect:?}"));
+
+    egui::Window::new("Status effects").show(contexts.ctx_mut()?, |ui| {
+        if ui.button("Reset").clicked() {
+            commands.run_system_cached(start_level_status_effects);
+            *sanity = Sanity::default();
+        }
+        ui.add(egui::Slider::new(&mut sanity.value, 0.0..=1.0).text("sanity"));
+
+        ui.collapsing("Drugs", |ui| {
+            for drug in drug_handles.iter().filter_map(|handle| drugs.get(handle)) {
+                if ui.button(&drug.name).clicked() {
+                    effects.apply_drug_effects(&drug.effects);
+                    sanity.change(drug.sanity);
+                }
+            }
+        });
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for effect in camera_effects {
+                let Some(status) = effects.effects.get_mut(&effect) else {
+                    continue;
+                };
+                ui.separator();
+                ui.strong(format!("{effect:?}"));
+                ui.add(egui::Slider::new(&mut status.intensity, 0.0..=3.0).text("intensity"));
+                ui.label(format!("{:?}", status.intensification));
+                if let Some(samples) = history.0.get(&effect) {
+                    intensity_graph(ui, samples);
+                }
+                for modifier in &status.modifiers {
+                    ui.label(format!(
+                        "{:?} {} for {:.1}s",
+                        modifier.rule,
+                        modifier.value,
+                        modifier.timer.remaining_secs()
+                    ));
+                }
+            }
+        });
+    });
+    Ok(())
+}
+
+/// Line graph of the intensity samples, scaled to their maximum
+fn intensity_graph(ui: &mut egui::Ui, samples: &VecDeque<f32>) {
+    let (rect, _) = ui.allocate_exact_size(GRAPH_SIZE, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2., ui.visuals().extreme_bg_color);
+
+    let max = samples.iter().copied().fold(1., f32::max);
+    let points = samples
+        .iter()
+        .enumerate()
+        .map(|(idx, intensity)| {
+            egui::pos2(
+                rect.left() + rect.width() * idx as f32 / HISTORY_LEN as f32,
+                rect.bottom() - rect.height() * intensity / max,
+            )
+        })
+        .collect();
+    painter.add(egui::Shape::line(
+        points,
+        egui::Stroke::new(1.5, ui.visuals().text_color()),
+    ));
+}
@@ -1,101 +1,202 @@
 use bevy::ecs::{lifecycle::HookContext, world::DeferredWorld};
+use feverdream_trap_core::asset_loader::{RonAsset, RonAssetLoader};
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    camera_controller::{CameraStatusEffects, status_effects::CameraEffect},
+    camera_controller::{
+        CameraStatusEffects,
+        status_effects::{CameraEffect, IntensificationCurve, ModifierRule},
+    },
+    character_controller::Sanity,
     interaction::{Interact, Interactable},
     prelude::*,
 };
 
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<Drug>()
+        .register_asset_loader(RonAssetLoader::<DrugAsset>::new());
+}
+
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-#[require(DrugInteraction {
-    effects: vec![(
-        CameraEffect::ChromaticAbberation,
-        vec![DrugEffectSet::Intensity { value: 0. }],
-    )]
-})]
-/// [`DrugInteraction`] preset because skein does not support editing collections (yet)
+#[require(DrugInteraction::named("clear_chromatic_aberration"))]
+/// Pill clearing the chromatic aberration, referenced by name from glTF files
 struct DrugClearChromaticAberrationIntensity;
 
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-#[cfg_attr(not(target_arch = "wasm32"), require(DrugInteraction {
-    effects: vec![(
-        CameraEffect::ScreenDarken,
-        vec![DrugEffectSet::Intensity { value: 0. }],
-    )]
-}))]
-/// [`DrugInteraction`] preset because skein does not support editing collections (yet)
+#[require(DrugInteraction::named("clear_screen_darken"))]
+/// Pill clearing the screen darkening, referenced by name from glTF files
 struct DrugClearScreenDarkenIntensity;
 
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-#[require(DrugInteraction {
-    effects: vec![
-        (
-            CameraEffect::ChromaticAbberation,
-            vec![DrugEffectSet::Intensity { value: 0. }],
-        ),
-        (
-            CameraEffect::ChromaticAbberation,
-            vec![DrugEffectSet::IntensificationFor { duration_secs: 5., value: 0. }],
-        )
-    ]
-})]
-/// [`DrugInteraction`] preset because skein does not support editing collections (yet)
+#[require(DrugInteraction::named("disable_chromatic_aberration_five_secs"))]
+/// Pill holding off the chromatic aberration for five seconds, referenced from glTF files
 struct DrugDisableChromaticAberrationFiveSecs;
 
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-#[cfg_attr(not(target_arch = "wasm32"), require(DrugInteraction {
-    effects: vec![
-        (
-            CameraEffect::ScreenDarken,
-            vec![DrugEffectSet::Intensity { value: 0. }],
-        ),
-        (
-            CameraEffect::ScreenDarken,
-            vec![DrugEffectSet::IntensificationFor { duration_secs: 5., value: 0. }],
-        )
-    ]
-}))]
-/// [`DrugInteraction`] preset because skein does not support editing collections (yet)
+#[require(DrugInteraction::named("disable_screen_darken_five_secs"))]
+/// Pill holding off the screen darkening for five seconds, referenced from glTF files
 struct DrugDisableScreenDarkenFiveSecs;
 
-#[derive(Component, Reflect, Debug, Default, Deref)]
+/// [`StoryState`] variable counting the drugs taken
+const PILLS_TAKEN: &str = "pills_taken";
+
+#[derive(Asset, TypePath, Debug, Serialize, Deserialize)]
+/// A drug described in `assets/drugs/<name>.drug`
+pub struct DrugAsset {
+    pub name: String,
+    /// Sound effect played when the drug is taken, relative to `assets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sound: Option<String>,
+    /// [`Sanity`] restored when the drug is taken
+    #[serde(default)]
+    pub sanity: f32,
+    pub effects: Vec<(CameraEffect, Vec<DrugEffectSet>)>,
+}
+
+impl DrugAsset {
+    pub const PATH: &str = "drugs";
+
+    pub fn path(name: &str) -> String {
+        format!("{}/{name}.{}", Self::PATH, Self::EXTENSION)
+    }
+}
+
+impl RonAsset for DrugAsset {
+    type Asset = Drug;
+    const EXTENSION: &str = "drug";
+
+    async fn load_dependencies(
+        mut self,
+        context: &mut bevy::asset::LoadContext<'_>,
+    ) -> Self::Asset {
+        for (_, sets) in &mut self.effects {
+            for set in sets {
+                if let DrugEffectSet::Curve { curve } = set {
+                    curve.sort_keyframes();
+                }
+            }
+        }
+        Drug {
+            name: self.name,
+            sound: self.sound.map(|sound| context.load(sound)),
+            sanity: self.sanity,
+            effects: self.effects,
+        }
+    }
+}
+
+#[derive(Asset, Reflect, Debug)]
+pub struct Drug {
+    pub name: String,
+    pub sound: Option<Handle<AudioSource>>,
+    pub sanity: f32,
+    pub effects: Vec<(CameraEffect, Vec<DrugEffectSet>)>,
+}
+
+#[derive(Component, Debug)]
+/// Handle to the [`Drug`] a [`DrugInteraction`] refers to
+struct DrugHandle(Handle<Drug>);
+
+#[derive(Component, Reflect, Debug, Default)]
 #[reflect(Default, Component)]
-#[require(Interactable, LevelComponent, RigidBody::Dynamic)]
+#[require(
+    Interactable,
+    InteractionPrompt::new("Take", "pill"),
+    LevelComponent,
+    RigidBody::Dynamic
+)]
 #[component(on_add)]
+/// Chunk elements attach this directly through their `components`, e.g. with the value
+/// `(drug: Some("clear_chromatic_aberration"))` or
+/// `(effects: [(ChromaticAbberation, [Intensity(value: 0.0)])])`
 pub struct DrugInteraction {
+    /// Name of a `.drug` asset in `assets/drugs`, applied before `effects`
+    drug: Option<String>,
     effects: Vec<(CameraEffect, Vec<DrugEffectSet>)>,
 }
 
 impl DrugInteraction {
+    pub fn named(drug: impl Into<String>) -> Self {
+        Self {
+            drug: Some(drug.into()),
+            effects: vec![],
+        }
+    }
+
     fn on_add<'a>(mut world: DeferredWorld<'a>, hook: HookContext) {
+        if let Some(name) = world
+            .get::<DrugInteraction>(hook.entity)
+            .and_then(|drug| drug.drug.clone())
+        {
+            let handle = world.resource::<AssetServer>().load(DrugAsset::path(&name));
+            world
+                .commands()
+                .entity(hook.entity)
+                .insert(DrugHandle(handle));
+        }
+
         world.commands().entity(hook.entity).observe(
             |event: On<Interact>,
              mut commands: Commands,
-             query: Query<&DrugInteraction>,
-             mut status_effects: ResMut<CameraStatusEffects>| {
-                if let Ok(drug_effects) = query.get(event.entity) {
-                    status_effects.apply_drug_effects(drug_effects);
-                    commands.entity(event.entity).despawn();
+             query: Query<(&DrugInteraction, Option<&DrugHandle>)>,
+             drugs: Res<Assets<Drug>>,
+             mut status_effects: ResMut<CameraStatusEffects>,
+             mut sanity: ResMut<Sanity>,
+             mut story: ResMut<StoryState>| {
+                let Ok((interaction, handle)) = query.get(event.entity) else {
+                    return;
+                };
+
+                if let Some(handle) = handle {
+                    match drugs.get(&handle.0) {
+                        Some(drug) => {
+                            status_effects.apply_drug_effects(&drug.effects);
+                            sanity.change(drug.sanity);
+                            if let Some(sound) = &drug.sound {
+                                commands.spawn(sound_effect(sound.clone()));
+                            }
+                        }
+                        None => warn!(
+                            "Drug {:?} is not loaded, only its inline effects apply",
+                            interaction.drug
+                        ),
+                    }
                 }
+
+                status_effects.apply_drug_effects(&interaction.effects);
+                story.increment(PILLS_TAKEN, 1);
+                commands.entity(event.entity).despawn();
             },
         );
     }
 }
 
-#[derive(Reflect, Debug)]
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
 #[reflect(Default)]
 pub enum DrugEffectSet {
     /// Set the effect intensity to `value`
     Intensity { value: f32 },
     /// Set the effect intensification to `value`
     Intensification { value: f32 },
-    /// Set the effect intensification to `value` for `duration_secs` seconds, then set it back to
-    /// where it was
+    /// Override the effect intensification with `value` for `duration_secs` seconds
     IntensificationFor { duration_secs: f32, value: f32 },
+    /// Stack a modifier combining `value` with the effect intensification by `rule` for
+    /// `duration_secs` seconds
+    Modifier {
+        rule: ModifierRule,
+        value: f32,
+        duration_secs: f32,
+    },
+    /// Set the effect intensification to follow `curve`
+    Curve { curve: IntensificationCurve },
+    /// Clamp the effect intensity to `min..=max`
+    Limits { min: f32, max: f32 },
+    /// Let the effect intensity decay towards `baseline` by the fraction `rate` per second
+    Decay { baseline: f32, rate: f32 },
 }
 
 impl Default for DrugEffectSet {
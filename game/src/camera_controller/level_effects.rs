@@ -0,0 +1,284 @@
+//! Per-level [`StatusEffect`](super::status_effects::StatusEffect) configuration from
+//! `assets/levels/<level>.effects`: the effects a level starts with and the gameplay consequences
+//! of high intensities.
+
+use feverdream_trap_core::asset_loader::{RonAsset, RonAssetLoader};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera_controller::{
+        CameraMarker, CameraStatusEffects,
+        status_effects::{CameraEffect, IntensificationCurve, intensify_effects},
+    },
+    character_controller::{Player, PlayerMover, spawn_point_transform},
+    chunk::SwapChunks,
+    prelude::*,
+    scene::GameSceneStorage,
+    screens::GameOverReason,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<LevelEffects>()
+        .register_asset_loader(RonAssetLoader::<LevelEffects>::new())
+        .load_resource::<LevelEffectsStash>()
+        .add_observer(on_status_threshold_crossed)
+        .add_systems(OnEnter(Screen::Gameplay), insert_status_thresholds)
+        .add_systems(
+            Update,
+            (
+                cross_status_thresholds
+                    .after(intensify_effects)
+                    .run_if(resource_exists::<StatusThresholds>),
+                fade_blackout,
+            )
+                .in_set(PausableSystems),
+        );
+}
+
+const BLACKOUT_SECS: f32 = 2.;
+
+/// Status effect configuration of a level
+#[derive(Asset, TypePath, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LevelEffects {
+    #[serde(default)]
+    pub starting: Vec<StartingEffect>,
+    #[serde(default)]
+    pub thresholds: Vec<EffectThreshold>,
+}
+
+impl LevelEffects {
+    pub fn path(level: &GameLevel) -> std::path::PathBuf {
+        level.path().with_extension(Self::EXTENSION)
+    }
+}
+
+impl RonAsset for LevelEffects {
+    type Asset = Self;
+    const EXTENSION: &str = "effects";
+
+    async fn load_dependencies(
+        mut self,
+        _context: &mut bevy::asset::LoadContext<'_>,
+    ) -> Self::Asset {
+        for curve in self
+            .starting
+            .iter_mut()
+            .filter_map(|starting| starting.intensification.as_mut())
+        {
+            curve.sort_keyframes();
+        }
+        self
+    }
+}
+
+/// State of `effect` when the level is started fresh, effects not listed start at their default
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
+pub struct StartingEffect {
+    pub effect: CameraEffect,
+    #[serde(default)]
+    pub intensity: f32,
+    /// Replaces the default intensification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub intensification: Option<IntensificationCurve>,
+}
+
+/// Triggers `consequence` once the `effect` intensity rises to `intensity`. It is armed again when
+/// the intensity drops below.
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
+pub struct EffectThreshold {
+    pub effect: CameraEffect,
+    pub intensity: f32,
+    pub consequence: ThresholdConsequence,
+}
+
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
+pub enum ThresholdConsequence {
+    /// Play `sound` as a warning, relative to `assets`
+    Whisper { sound: String },
+    /// Swap two chunks of the level
+    HallucinationSwap { chunk_a: u32, chunk_b: u32 },
+    /// Black out and respawn at the level's spawn point, clearing the effect
+    Blackout,
+    /// End the run
+    Succumb,
+}
+
+#[derive(Asset, TypePath, Resource, Debug, Clone)]
+pub struct LevelEffectsStash {
+    #[dependency]
+    handles: Vec<Handle<LevelEffects>>,
+    /// Parallel to `handles`
+    levels: Vec<GameLevel>,
+}
+
+impl LevelEffectsStash {
+    fn get<'a>(
+        &self,
+        level_effects: &'a Assets<LevelEffects>,
+        level: &GameLevel,
+    ) -> Option<&'a LevelEffects> {
+        let idx = self.levels.iter().position(|l| l == level)?;
+        level_effects.get(&self.handles[idx])
+    }
+}
+
+impl FromWorld for LevelEffectsStash {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server = world.resource::<AssetServer>();
+        let levels = GameLevel::ALL.to_vec();
+        let handles = levels
+            .iter()
+            .map(|level| asset_server.load(LevelEffects::path(level)))
+            .collect();
+        Self { handles, levels }
+    }
+}
+
+/// The current level's thresholds and whether each of them is armed
+#[derive(Resource, Debug)]
+pub struct StatusThresholds(Vec<(EffectThreshold, bool)>);
+
+#[derive(Event, Debug, Clone)]
+/// An [`EffectThreshold`] was crossed
+pub struct StatusThresholdCrossed {
+    pub effect: CameraEffect,
+    pub consequence: ThresholdConsequence,
+}
+
+#[derive(Debug, Component)]
+struct BlackoutFade(Timer);
+
+fn insert_status_thresholds(
+    mut commands: Commands,
+    stash: Res<LevelEffectsStash>,
+    level_effects: Res<Assets<LevelEffects>>,
+    current_level: Res<CurrentLevel>,
+) {
+    let thresholds = stash
+        .get(&level_effects, &current_level.0)
+        .map(|effects| {
+            effects
+                .thresholds
+                .iter()
+                .map(|threshold| (threshold.clone(), true))
+                .collect()
+        })
+        .unwrap_or_default();
+    commands.insert_resource(StatusThresholds(thresholds));
+}
+
+/// Reset the [`CameraStatusEffects`] to the current level's [`StartingEffect`]s
+pub fn start_level_status_effects(
+    mut commands: Commands,
+    stash: Res<LevelEffectsStash>,
+    level_effects: Res<Assets<LevelEffects>>,
+    current_level: Res<CurrentLevel>,
+) {
+    let mut effects = CameraStatusEffects::default();
+    for starting in stash
+        .get(&level_effects, &current_level.0)
+        .map_or(&[][..], |level| &level.starting)
+    {
+        let Some(status) = effects.effects.get_mut(&starting.effect) else {
+            continue;
+        };
+        status.intensity = starting.intensity;
+        if let Some(curve) = &starting.intensification {
+            status.set_intensification(curve.clone());
+        }
+    }
+    commands.insert_resource(effects);
+}
+
+fn cross_status_thresholds(
+    mut commands: Commands,
+    effects: Res<CameraStatusEffects>,
+    mut thresholds: ResMut<StatusThresholds>,
+) {
+    for (threshold, armed) in &mut thresholds.0 {
+        let Some(status) = effects.effects.get(&threshold.effect) else {
+            continue;
+        };
+        if status.intensity < threshold.intensity {
+            *armed = true;
+        } else if *armed {
+            *armed = false;
+            commands.trigger(StatusThresholdCrossed {
+                effect: threshold.effect,
+                consequence: threshold.consequence.clone(),
+            });
+        }
+    }
+}
+
+fn on_status_threshold_crossed(
+    event: On<StatusThresholdCrossed>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut effects: ResMut<CameraStatusEffects>,
+    spawn_point: Query<
+        (&Transform, &SpawnMarker),
+        (With<Chunk>, Without<Player>, Without<CameraMarker>),
+    >,
+    mut player: PlayerMover,
+    mut scene_storage: ResMut<GameSceneStorage>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    info!("Status threshold crossed: {event:?}");
+    match &event.consequence {
+        ThresholdConsequence::Whisper { sound } => {
+            commands.spawn(sound_effect(asset_server.load(sound)));
+        }
+        ThresholdConsequence::HallucinationSwap { chunk_a, chunk_b } => {
+            commands.trigger(SwapChunks(ChunkId(*chunk_a), ChunkId(*chunk_b)));
+        }
+        ThresholdConsequence::Blackout => {
+            if let Some(status) = effects.effects.get_mut(&event.effect) {
+                status.intensity = 0.;
+            }
+            player.place(spawn_point_transform(spawn_point.single().ok()).translation);
+            commands.spawn((
+                Name::new("Blackout Fade"),
+                BlackoutFade(Timer::from_seconds(BLACKOUT_SECS, TimerMode::Once)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: percent(100),
+                    height: percent(100),
+                    ..default()
+                },
+                GlobalZIndex(1),
+                BackgroundColor(Color::BLACK),
+                Pickable::IGNORE,
+                DespawnOnExit(Screen::Gameplay),
+            ));
+        }
+        ThresholdConsequence::Succumb => {
+            commands.insert_resource(GameOverReason::Succumbed);
+            // Don't let the run be continued right at the threshold
+            scene_storage.handle = None;
+            scene_storage.skip_save = true;
+            // The saved scene is loaded again on the next start, remove it along with the handle
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Err(error) = std::fs::remove_file(crate::scene::scene_file_path())
+                && error.kind() != std::io::ErrorKind::NotFound
+            {
+                error!("Failed to remove the saved scene: {error}");
+            }
+            next_screen.set(Screen::GameOver);
+        }
+    }
+}
+
+fn fade_blackout(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut fades: Query<(Entity, &mut BackgroundColor, &mut BlackoutFade)>,
+) {
+    for (entity, mut background, mut fade) in &mut fades {
+        fade.0.tick(time.delta());
+        background.0.set_alpha(1. - fade.0.fraction());
+        if fade.0.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
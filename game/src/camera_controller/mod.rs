@@ -4,23 +4,39 @@ use feverdream_trap_core::prelude::cursor::{cursor_grab, cursor_ungrab};
 
 use crate::prelude::*;
 
+mod audio_distortion;
+mod camera_warp;
+#[cfg(feature = "dev_native")]
+pub(crate) mod debug_panel;
 mod drugs;
+mod level_effects;
+#[cfg(not(target_arch = "wasm32"))]
+mod post_effects;
 #[cfg(not(target_arch = "wasm32"))]
 mod screen_darken;
 mod setup;
 mod status_effects;
 
+pub use camera_warp::CameraShake;
+pub use drugs::DrugInteraction;
+pub use level_effects::start_level_status_effects;
 pub use setup::spawn_camera;
-pub use status_effects::CameraStatusEffects;
+pub use status_effects::{CameraEffect, CameraStatusEffects};
 
 pub struct CameraControllerPlugin;
 
 impl Plugin for CameraControllerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            audio_distortion::plugin,
+            camera_warp::plugin,
+            drugs::plugin,
             status_effects::plugin,
+            level_effects::plugin,
             #[cfg(not(target_arch = "wasm32"))]
             screen_darken::plugin,
+            #[cfg(not(target_arch = "wasm32"))]
+            post_effects::plugin,
         ));
 
         app.insert_resource(CameraSettings {
@@ -51,10 +67,11 @@ struct CameraSettings {
         },
         ..Default::default()
     },
+    camera_warp::CameraWarp,
 )]
 #[cfg_attr(
     not(target_arch = "wasm32"),
-    require(screen_darken::ScreenDarkenEffect)
+    require(screen_darken::ScreenDarkenEffect, post_effects::PostEffects)
 )]
 #[component(on_add = CameraStatusEffects::add)]
 pub struct CameraMarker;
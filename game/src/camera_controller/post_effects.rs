@@ -0,0 +1,57 @@
+use bevy::{
+    core_pipeline::{
+        core_3d::graph::Node3d,
+        fullscreen_material::{FullscreenMaterial, FullscreenMaterialPlugin},
+    },
+    render::{
+        extract_component::ExtractComponent, render_graph::RenderLabel as _,
+        render_resource::ShaderType,
+    },
+    shader::ShaderRef,
+};
+
+use crate::{
+    camera_controller::{CameraMarker, CameraStatusEffects, status_effects::CameraEffect},
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(FullscreenMaterialPlugin::<PostEffects>::default());
+}
+
+/// Vignette, film grain, desaturation and double vision in a single fullscreen pass, see
+/// [`ScreenDarkenEffect`](super::screen_darken::ScreenDarkenEffect)
+#[derive(Component, ExtractComponent, Clone, Copy, ShaderType, Default)]
+pub struct PostEffects {
+    vignette: f32,
+    grain: f32,
+    desaturation: f32,
+    double_vision: f32,
+    time: f32,
+}
+
+impl FullscreenMaterial for PostEffects {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/post_effects.wgsl".into()
+    }
+
+    fn node_edges() -> Vec<bevy::render::render_graph::InternedRenderLabel> {
+        vec![
+            Node3d::Tonemapping.intern(),
+            Self::node_label().intern(),
+            Node3d::EndMainPassPostProcessing.intern(),
+        ]
+    }
+}
+
+pub fn apply_post_effects_intensity(
+    time: Res<Time>,
+    effects: Res<CameraStatusEffects>,
+    mut effect: Single<&mut PostEffects, With<CameraMarker>>,
+) {
+    effect.vignette = effects.intensity(CameraEffect::Vignette);
+    effect.grain = effects.intensity(CameraEffect::FilmGrain);
+    effect.desaturation = effects.intensity(CameraEffect::Desaturation);
+    effect.double_vision = effects.intensity(CameraEffect::DoubleVision);
+    effect.time = time.elapsed_secs_wrapped();
+}
@@ -4,14 +4,15 @@ use bevy::{
     post_process::effect_stack::ChromaticAberration,
     render::render_resource::{Extent3d, TextureDimension, TextureFormat},
 };
+use serde::{Deserialize, Serialize};
 
 #[cfg(not(target_arch = "wasm32"))]
-use crate::camera_controller::screen_darken::apply_screen_darken_intensity;
+use crate::camera_controller::{
+    post_effects::apply_post_effects_intensity, screen_darken::apply_screen_darken_intensity,
+};
 use crate::{
-    camera_controller::{
-        CameraMarker,
-        drugs::{DrugEffectSet, DrugInteraction},
-    },
+    camera_controller::{CameraMarker, drugs::DrugEffectSet},
+    character_controller::Sanity,
     prelude::*,
 };
 
@@ -24,6 +25,8 @@ pub(super) fn plugin(app: &mut App) {
                 apply_chromatic_aberration_intensity,
                 #[cfg(not(target_arch = "wasm32"))]
                 apply_screen_darken_intensity,
+                #[cfg(not(target_arch = "wasm32"))]
+                apply_post_effects_intensity,
             )
                 .after(intensify_effects),
         )
@@ -31,13 +34,135 @@ pub(super) fn plugin(app: &mut App) {
     );
 }
 
-#[derive(Reflect, Debug, PartialEq, Eq, Hash, Default)]
+/// Effects tracked on every target so drug and level files load everywhere, the screen darken and
+/// post process rendering only exists natively
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[reflect(Default)]
 pub enum CameraEffect {
     #[default]
     ChromaticAbberation,
-    #[cfg(not(target_arch = "wasm32"))]
     ScreenDarken,
+    Vignette,
+    FilmGrain,
+    Desaturation,
+    DoubleVision,
+    /// Field of view oscillation
+    FovBreathing,
+    /// Camera roll and drift
+    Sway,
+}
+
+/// Increase of a [`StatusEffect`] intensity per second, over the seconds since the curve was set
+#[derive(Reflect, Debug, Clone, Serialize, Deserialize)]
+pub enum IntensificationCurve {
+    Linear {
+        rate: f32,
+    },
+    /// `rate` growing by the factor `e^(growth * t)`
+    Exponential {
+        rate: f32,
+        growth: f32,
+    },
+    /// `rate` pulsing by `amplitude` once every `period_secs`
+    Sine {
+        rate: f32,
+        amplitude: f32,
+        period_secs: f32,
+    },
+    /// Rates interpolated between `(secs, rate)` keyframes sorted by time, holding the last rate
+    Keyframes {
+        keyframes: Vec<(f32, f32)>,
+    },
+}
+
+impl Default for IntensificationCurve {
+    fn default() -> Self {
+        Self::Linear { rate: 0.01 }
+    }
+}
+
+/// Limits `e^(growth * t)` of [`IntensificationCurve::Exponential`]
+const MAX_EXPONENT: f32 = 10.;
+
+impl IntensificationCurve {
+    /// Sort the keyframes by time, dropping those without a finite time or rate
+    pub fn sort_keyframes(&mut self) {
+        let Self::Keyframes { keyframes } = self else {
+            return;
+        };
+        let count = keyframes.len();
+        keyframes.retain(|(secs, rate)| secs.is_finite() && rate.is_finite());
+        if keyframes.len() < count {
+            warn!(
+                "Dropped {} intensification keyframes that aren't finite",
+                count - keyframes.len()
+            );
+        }
+        keyframes.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    }
+
+    /// The intensification `secs` seconds after the curve was set
+    pub fn rate(&self, secs: f32) -> f32 {
+        match self {
+            Self::Linear { rate } => *rate,
+            Self::Exponential { rate, growth } => rate * (growth * secs).min(MAX_EXPONENT).exp(),
+            Self::Sine {
+                rate,
+                amplitude,
+                period_secs,
+            } => rate + amplitude * (secs / period_secs.max(f32::EPSILON) * TAU).sin(),
+            Self::Keyframes { keyframes } => {
+                match keyframes.iter().position(|(time, _)| *time > secs) {
+                    Some(0) => keyframes[0].1,
+                    Some(idx) => {
+                        let (from_secs, from_rate) = keyframes[idx - 1];
+                        let (to_secs, to_rate) = keyframes[idx];
+                        // Unsorted keyframes can form segments without length
+                        if to_secs <= from_secs {
+                            return to_rate;
+                        }
+                        from_rate
+                            + (to_rate - from_rate) * (secs - from_secs) / (to_secs - from_secs)
+                    }
+                    None => keyframes.last().map_or(0., |(_, rate)| *rate),
+                }
+            }
+        }
+    }
+}
+
+/// How an [`EffectModifier`] combines with the intensification below it in the stack
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModifierRule {
+    Override,
+    Add,
+    Multiply,
+}
+
+/// Temporary change of a [`StatusEffect`] intensification
+#[derive(Reflect, Debug, Clone)]
+pub struct EffectModifier {
+    pub rule: ModifierRule,
+    pub value: f32,
+    pub timer: Timer,
+}
+
+impl EffectModifier {
+    pub fn new(rule: ModifierRule, value: f32, duration_secs: f32) -> Self {
+        Self {
+            rule,
+            value,
+            timer: Timer::from_seconds(duration_secs, TimerMode::Once),
+        }
+    }
+
+    fn apply(&self, speed: f32) -> f32 {
+        match self.rule {
+            ModifierRule::Override => self.value,
+            ModifierRule::Add => speed + self.value,
+            ModifierRule::Multiply => speed * self.value,
+        }
+    }
 }
 
 #[derive(Reflect, Debug)]
@@ -45,20 +170,71 @@ pub struct StatusEffect {
     /// Current effect intensity
     pub intensity: f32,
     /// Increase of `self.intensity` per second
-    intensification_speed: f32,
-    temporary_intensification_overwrite: Option<(Timer, f32)>,
+    pub intensification: IntensificationCurve,
+    /// Seconds since `self.intensification` was set
+    curve_secs: f32,
+    /// Lower bound of `self.intensity`
+    min: f32,
+    /// Upper bound of `self.intensity`
+    max: f32,
+    /// Intensity `self.intensity` decays towards
+    baseline: f32,
+    /// Fraction of the distance to `self.baseline` decaying per second
+    decay: f32,
+    /// Applied to the intensification in order, removed once their timer finishes
+    pub modifiers: Vec<EffectModifier>,
 }
 
 impl Default for StatusEffect {
     fn default() -> Self {
         Self {
             intensity: 0.,
-            intensification_speed: 0.01,
-            temporary_intensification_overwrite: None,
+            intensification: default(),
+            curve_secs: 0.,
+            min: 0.,
+            max: f32::MAX,
+            baseline: 0.,
+            decay: 0.,
+            modifiers: vec![],
         }
     }
 }
 
+impl StatusEffect {
+    /// An effect that stays at zero until a drug changes it
+    fn dormant() -> Self {
+        Self {
+            intensification: IntensificationCurve::Linear { rate: 0. },
+            ..default()
+        }
+    }
+
+    pub(super) fn set_intensification(&mut self, curve: IntensificationCurve) {
+        self.intensification = curve;
+        self.curve_secs = 0.;
+    }
+
+    /// `factor` scales the intensification while it makes the effect grow
+    fn intensify(&mut self, delta: Duration, factor: f32) {
+        let delta_secs = delta.as_secs_f32();
+        self.curve_secs += delta_secs;
+        let mut speed = self.intensification.rate(self.curve_secs);
+        for modifier in &mut self.modifiers {
+            modifier.timer.tick(delta);
+            speed = modifier.apply(speed);
+        }
+        self.modifiers
+            .retain(|modifier| !modifier.timer.is_finished());
+        if speed > 0. {
+            speed *= factor;
+        }
+        self.intensity += delta_secs * speed;
+        self.intensity +=
+            (self.baseline - self.intensity) * (1. - (-self.decay * delta_secs).exp());
+        self.intensity = self.intensity.clamp(self.min, self.max);
+    }
+}
+
 #[derive(Resource, Reflect, Debug)]
 #[reflect(Resource)]
 /// The contained [`StatusEffect`] values can be controlled by drugs
@@ -71,32 +247,72 @@ impl Default for CameraStatusEffects {
         Self {
             effects: HashMap::from_iter([
                 (CameraEffect::ChromaticAbberation, Default::default()),
-                #[cfg(not(target_arch = "wasm32"))]
                 (CameraEffect::ScreenDarken, Default::default()),
+                (CameraEffect::Vignette, StatusEffect::dormant()),
+                (CameraEffect::FilmGrain, StatusEffect::dormant()),
+                (CameraEffect::Desaturation, StatusEffect::dormant()),
+                (CameraEffect::DoubleVision, StatusEffect::dormant()),
+                (CameraEffect::FovBreathing, StatusEffect::dormant()),
+                (CameraEffect::Sway, StatusEffect::dormant()),
             ]),
         }
     }
 }
 
 impl CameraStatusEffects {
-    pub(super) fn apply_drug_effects(&mut self, drug_effects: &DrugInteraction) {
-        for (camera_effect, drug_effects) in drug_effects.iter() {
-            let status = self.effects.get_mut(camera_effect).unwrap();
+    /// Intensity of `effect`, 0 if it is missing, e.g. from a save predating the effect
+    pub fn intensity(&self, effect: CameraEffect) -> f32 {
+        self.effects
+            .get(&effect)
+            .map_or(0., |status| status.intensity)
+    }
+
+    pub(crate) fn apply_drug_effects(
+        &mut self,
+        drug_effects: &[(CameraEffect, Vec<DrugEffectSet>)],
+    ) {
+        for (camera_effect, drug_effects) in drug_effects {
+            let Some(status) = self.effects.get_mut(camera_effect) else {
+                warn!(
+                    "Drug effect {camera_effect:?} is missing from the status effects, skipping it"
+                );
+                continue;
+            };
             for effect in drug_effects {
                 match effect {
                     DrugEffectSet::Intensity { value } => status.intensity = *value,
                     DrugEffectSet::Intensification { value } => {
-                        status.intensification_speed = *value
+                        status.set_intensification(IntensificationCurve::Linear { rate: *value })
+                    }
+                    DrugEffectSet::Curve { curve } => status.set_intensification(curve.clone()),
+                    DrugEffectSet::Limits { min, max } => {
+                        // Drugs are designer data, clamping to invalid limits would panic
+                        if min > max || min.is_nan() || max.is_nan() {
+                            warn!("Ignoring invalid {camera_effect:?} limits {min}..={max}");
+                            continue;
+                        }
+                        status.min = *min;
+                        status.max = *max;
+                    }
+                    DrugEffectSet::Decay { baseline, rate } => {
+                        status.baseline = *baseline;
+                        status.decay = *rate;
                     }
                     DrugEffectSet::IntensificationFor {
                         duration_secs,
                         value,
-                    } => {
-                        status.temporary_intensification_overwrite = Some((
-                            Timer::new(Duration::from_secs_f32(*duration_secs), TimerMode::Once),
-                            *value,
-                        ))
-                    }
+                    } => status.modifiers.push(EffectModifier::new(
+                        ModifierRule::Override,
+                        *value,
+                        *duration_secs,
+                    )),
+                    DrugEffectSet::Modifier {
+                        rule,
+                        value,
+                        duration_secs,
+                    } => status
+                        .modifiers
+                        .push(EffectModifier::new(*rule, *value, *duration_secs)),
                 }
             }
         }
@@ -140,26 +356,15 @@ impl CameraStatusEffects {
     }
 }
 
-fn intensify_effects(time: Res<Time>, mut effects: ResMut<CameraStatusEffects>) {
-    let delta = time.delta_secs();
-    for StatusEffect {
-        intensity,
-        intensification_speed,
-        temporary_intensification_overwrite,
-    } in effects.effects.values_mut()
-    {
-        *intensity += delta
-            * match temporary_intensification_overwrite {
-                Some((timer, overwrite)) => {
-                    timer.tick(time.delta());
-                    let speed = *overwrite;
-                    if timer.just_finished() {
-                        *temporary_intensification_overwrite = None;
-                    }
-                    speed
-                }
-                None => *intensification_speed,
-            };
+/// Lost [`Sanity`] makes the effects grow faster
+pub(super) fn intensify_effects(
+    time: Res<Time>,
+    sanity: Res<Sanity>,
+    mut effects: ResMut<CameraStatusEffects>,
+) {
+    let factor = sanity.intensification_factor();
+    for status in effects.effects.values_mut() {
+        status.intensify(time.delta(), factor);
     }
 }
 
@@ -167,5 +372,5 @@ fn apply_chromatic_aberration_intensity(
     effects: Res<CameraStatusEffects>,
     mut effect: Single<&mut ChromaticAberration, With<CameraMarker>>,
 ) {
-    effect.intensity = effects.effects[&CameraEffect::ChromaticAbberation].intensity;
+    effect.intensity = effects.intensity(CameraEffect::ChromaticAbberation);
 }
@@ -0,0 +1,229 @@
+//! Status effects impairing the player's control, see [`ControlInfluences`]
+
+use bevy_ahoy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+use rand::{Rng, rngs::StdRng};
+
+use crate::{
+    camera_controller::{CameraEffect, CameraStatusEffects},
+    character_controller::Player,
+    chunk::RunSeed,
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ControlInfluences>().add_systems(
+        Update,
+        (
+            // The seed changes when a run starts or a saved one is loaded
+            seed_stumbles.run_if(resource_changed::<RunSeed>),
+            (impair_movement, impair_look, stumble)
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(PausableSystems),
+        )
+            .chain(),
+    );
+}
+
+/// Scale of the [`RotateCamera`] action without impairment
+pub(super) const LOOK_SCALE: f32 = 0.04;
+/// Fraction of the movement speed lost at full slowdown
+const MAX_SLOWDOWN: f32 = 0.8;
+/// Fraction the acceleration swings by at full drunkenness, so the player lurches and drifts
+const DRUNK_ACCELERATION_SWING: f32 = 0.9;
+/// Fraction the look sensitivity drifts by at full drift
+const SENSITIVITY_DRIFT: f32 = 0.6;
+/// Look smoothing at the first delay step, lower values delay more
+const LOOK_NUDGE: f32 = 12.;
+/// Delay steps per unit of delay amount
+const LOOK_DELAY_STEPS: f32 = 4.;
+/// Speed of a stumble
+const STUMBLE_SPEED: f32 = 3.;
+/// [`RunSeed::rng`] stream of the stumbles
+const STUMBLE_RNG_STREAM: u64 = 1;
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerControl {
+    /// Scale down the controller's speed
+    SlowMovement,
+    /// Let the controller's acceleration swing up and down
+    DrunkMovement,
+    /// Let the look sensitivity drift up and down
+    SensitivityDrift,
+    /// Invert the look input once the amount reaches 1
+    InvertedLook,
+    /// Smooth the look input so it lags behind
+    DelayedLook,
+    /// Randomly push the player, on average `amount` times per second
+    Stumble,
+}
+
+/// `effect` impairs `control` by its intensity times `scale`
+#[derive(Reflect, Debug, Clone)]
+pub struct ControlInfluence {
+    pub effect: CameraEffect,
+    pub control: PlayerControl,
+    pub scale: f32,
+}
+
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+/// How the [`CameraStatusEffects`] impair the player's control
+pub struct ControlInfluences(pub Vec<ControlInfluence>);
+
+impl Default for ControlInfluences {
+    fn default() -> Self {
+        Self(vec![
+            ControlInfluence {
+                effect: CameraEffect::ChromaticAbberation,
+                control: PlayerControl::SensitivityDrift,
+                scale: 0.3,
+            },
+            ControlInfluence {
+                effect: CameraEffect::ScreenDarken,
+                control: PlayerControl::SlowMovement,
+                scale: 0.3,
+            },
+            ControlInfluence {
+                effect: CameraEffect::DoubleVision,
+                control: PlayerControl::InvertedLook,
+                scale: 1.,
+            },
+            ControlInfluence {
+                effect: CameraEffect::FovBreathing,
+                control: PlayerControl::DelayedLook,
+                scale: 0.5,
+            },
+            ControlInfluence {
+                effect: CameraEffect::Sway,
+                control: PlayerControl::DrunkMovement,
+                scale: 0.5,
+            },
+            ControlInfluence {
+                effect: CameraEffect::Sway,
+                control: PlayerControl::Stumble,
+                scale: 0.1,
+            },
+        ])
+    }
+}
+
+impl ControlInfluences {
+    /// Sum of the scaled intensities influencing `control`
+    fn amount(&self, effects: &CameraStatusEffects, control: PlayerControl) -> f32 {
+        self.0
+            .iter()
+            .filter(|influence| influence.control == control)
+            .filter_map(|influence| {
+                let status = effects.effects.get(&influence.effect)?;
+                Some(status.intensity * influence.scale)
+            })
+            .sum::<f32>()
+            .max(0.)
+    }
+}
+
+/// The [`PlayerControl::DelayedLook`] step of the look action's [`SmoothNudge`]
+#[derive(Component, Debug)]
+struct LookDelayStep(u32);
+
+/// The player's [`CharacterController`] settings without impairment
+#[derive(Component, Debug)]
+struct UnimpairedController {
+    speed: f32,
+    acceleration_hz: f32,
+}
+
+/// Rng of the [`PlayerControl::Stumble`] pushes, seeded from the [`RunSeed`]
+#[derive(Resource, Debug)]
+struct StumbleRng(StdRng);
+
+fn seed_stumbles(mut commands: Commands, run_seed: Res<RunSeed>) {
+    commands.insert_resource(StumbleRng(run_seed.rng(STUMBLE_RNG_STREAM)));
+}
+
+fn impair_movement(
+    mut commands: Commands,
+    time: Res<Time>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<ControlInfluences>,
+    mut player: Query<
+        (
+            Entity,
+            &mut CharacterController,
+            Option<&UnimpairedController>,
+        ),
+        With<Player>,
+    >,
+) {
+    let slow = influences
+        .amount(&effects, PlayerControl::SlowMovement)
+        .min(1.);
+    let drunk = influences
+        .amount(&effects, PlayerControl::DrunkMovement)
+        .min(1.);
+    let wobble = (time.elapsed_secs_wrapped() * 0.8).sin();
+
+    for (entity, mut controller, unimpaired) in &mut player {
+        let Some(unimpaired) = unimpaired else {
+            commands.entity(entity).insert(UnimpairedController {
+                speed: controller.speed,
+                acceleration_hz: controller.acceleration_hz,
+            });
+            continue;
+        };
+        controller.speed = unimpaired.speed * (1. - slow * MAX_SLOWDOWN);
+        controller.acceleration_hz =
+            unimpaired.acceleration_hz * (1. + wobble * drunk * DRUNK_ACCELERATION_SWING);
+    }
+}
+
+fn impair_look(
+    mut commands: Commands,
+    time: Res<Time>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<ControlInfluences>,
+    mut look: Query<(Entity, &mut Scale, Option<&LookDelayStep>), With<Action<RotateCamera>>>,
+) {
+    let drift = influences
+        .amount(&effects, PlayerControl::SensitivityDrift)
+        .min(1.);
+    let sensitivity = 1. + drift * SENSITIVITY_DRIFT * (time.elapsed_secs_wrapped() * 0.37).sin();
+    let inverted = influences.amount(&effects, PlayerControl::InvertedLook) >= 1.;
+    let direction = if inverted { -1. } else { 1. };
+
+    // Changing the smoothing resets it, so only do that in steps
+    let delay = influences.amount(&effects, PlayerControl::DelayedLook);
+    let step = (delay * LOOK_DELAY_STEPS).floor() as u32;
+
+    for (entity, mut scale, delay_step) in &mut look {
+        *scale = Scale::splat(LOOK_SCALE * sensitivity * direction);
+        if delay_step.map_or(0, |delay_step| delay_step.0) == step {
+            continue;
+        }
+        let mut action = commands.entity(entity);
+        action.insert(LookDelayStep(step));
+        if step == 0 {
+            action.remove::<SmoothNudge>();
+        } else {
+            action.insert(SmoothNudge::new(LOOK_NUDGE / step as f32));
+        }
+    }
+}
+
+fn stumble(
+    time: Res<Time>,
+    effects: Res<CameraStatusEffects>,
+    influences: Res<ControlInfluences>,
+    mut rng: ResMut<StumbleRng>,
+    mut player: Query<&mut LinearVelocity, With<Player>>,
+) {
+    let chance = influences.amount(&effects, PlayerControl::Stumble) * time.delta_secs();
+    if rng.0.random::<f32>() >= chance {
+        return;
+    }
+    let angle = rng.0.random::<f32>() * TAU;
+    for mut velocity in &mut player {
+        velocity.0 += Vec3::new(angle.cos(), 0., angle.sin()) * STUMBLE_SPEED;
+    }
+}
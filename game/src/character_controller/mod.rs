@@ -1,10 +1,16 @@
-use bevy::ecs::{lifecycle::HookContext, world::DeferredWorld};
+use bevy::ecs::{lifecycle::HookContext, system::SystemParam, world::DeferredWorld};
 use bevy_ahoy::prelude::*;
 use bevy_enhanced_input::prelude::*;
 use feverdream_trap_core::physics::GameLayer;
 
+mod impairment;
+mod sanity;
+
+pub use sanity::Sanity;
+
 use crate::{
     camera_controller::{CameraMarker, CameraTargetCharacterController},
+    interaction::{ElevatorRide, InteractAction, ThrowAction},
     prelude::*,
 };
 
@@ -12,16 +18,23 @@ pub struct CharacterControllerPlugin;
 
 impl Plugin for CharacterControllerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((EnhancedInputPlugin, AhoyPlugins::default()))
-            .add_input_context::<PlayerInput>()
-            .add_systems(
-                OnEnter(Menu::None),
-                add_player_input.run_if(in_state(Screen::Gameplay)),
-            )
-            .add_systems(
-                OnExit(Menu::None),
-                remove_player_input.run_if(in_state(Screen::Gameplay)),
-            );
+        app.add_plugins((
+            EnhancedInputPlugin,
+            AhoyPlugins::default(),
+            impairment::plugin,
+            sanity::plugin,
+        ))
+        .add_input_context::<PlayerInput>()
+        .add_systems(
+            OnEnter(Menu::None),
+            // The elevator ride gives the input back once it arrives
+            add_player_input
+                .run_if(in_state(Screen::Gameplay).and(not(resource_exists::<ElevatorRide>))),
+        )
+        .add_systems(
+            OnExit(Menu::None),
+            remove_player_input.run_if(in_state(Screen::Gameplay)),
+        );
     }
 }
 
@@ -63,12 +76,20 @@ impl PlayerInput {
                 ),
                 (
                     Action::<RotateCamera>::new(),
-                    Scale::splat(0.04),
+                    Scale::splat(impairment::LOOK_SCALE),
                     Bindings::spawn((
                         Spawn(Binding::mouse_motion()),
                         Axial::right_stick()
                     ))
                 ),
+                (
+                    Action::<InteractAction>::new(),
+                    bindings![MouseButton::Left, KeyCode::KeyE, GamepadButton::South]
+                ),
+                (
+                    Action::<ThrowAction>::new(),
+                    bindings![MouseButton::Right, KeyCode::KeyQ, GamepadButton::East]
+                ),
             ]));
     }
 }
@@ -78,9 +99,25 @@ pub fn spawn_player(
     spawn_point: Query<(&Transform, &SpawnMarker), With<Chunk>>,
     camera: Single<Entity, With<CameraMarker>>,
 ) {
-    // Retrieve the spawn marker transform
-    let spawn_transform = match spawn_point.single() {
-        Ok((chunk_transform, SpawnMarker(point))) => {
+    let spawn_transform = spawn_point_transform(spawn_point.single().ok());
+
+    info!("Spawning player at {}", spawn_transform.translation.xyz());
+
+    // Spawn the player entity
+    let player = commands
+        .spawn((Name::new("Player"), spawn_transform, Player, PlayerInput))
+        .id();
+
+    // Spawn the camera
+    commands
+        .entity(*camera)
+        .insert(CameraTargetCharacterController(player));
+}
+
+/// Player transform at the spawn marker of the level, if there is one
+pub(crate) fn spawn_point_transform(spawn_point: Option<(&Transform, &SpawnMarker)>) -> Transform {
+    match spawn_point {
+        Some((chunk_transform, SpawnMarker(point))) => {
             let chunk_position = chunk_transform.translation;
 
             info!("chunk pos: {}", chunk_position.xyz());
@@ -92,23 +129,62 @@ pub fn spawn_player(
                 chunk_position.z + point.translation.z,
             )
         }
-        Err(_) => {
+        None => {
             warn!("spawn point not found");
             Transform::from_xyz(0.0, 1.0, 0.0)
         }
-    };
+    }
+}
 
-    info!("Spawning player at {}", spawn_transform.translation.xyz());
+/// Moves the player together with its physics position and the camera following it
+#[derive(SystemParam)]
+pub(crate) struct PlayerMover<'w, 's> {
+    player: Query<
+        'w,
+        's,
+        (
+            &'static mut Transform,
+            Option<&'static mut Position>,
+            Option<&'static mut LinearVelocity>,
+        ),
+        With<Player>,
+    >,
+    camera: Query<'w, 's, &'static mut Transform, (With<CameraMarker>, Without<Player>)>,
+}
 
-    // Spawn the player entity
-    let player = commands
-        .spawn((Name::new("Player"), spawn_transform, Player, PlayerInput))
-        .id();
+impl PlayerMover<'_, '_> {
+    pub(crate) fn translation(&self) -> Option<Vec3> {
+        self.player
+            .single()
+            .ok()
+            .map(|(transform, ..)| transform.translation)
+    }
 
-    // Spawn the camera
-    commands
-        .entity(*camera)
-        .insert(CameraTargetCharacterController(player));
+    /// Move the player and the camera by `delta`, keeping the player's velocity
+    pub(crate) fn shift(&mut self, delta: Vec3) {
+        let Ok((mut transform, position, _)) = self.player.single_mut() else {
+            return;
+        };
+        // The physics position is written back to the transform, so both have to move
+        transform.translation += delta;
+        if let Some(mut position) = position {
+            position.0 += delta;
+        }
+        for mut camera in &mut self.camera {
+            camera.translation += delta;
+        }
+    }
+
+    /// Place the player at `translation` and stop it
+    pub(crate) fn place(&mut self, translation: Vec3) {
+        let Some(current) = self.translation() else {
+            return;
+        };
+        self.shift(translation - current);
+        if let Ok((.., Some(mut velocity))) = self.player.single_mut() {
+            velocity.0 = Vec3::ZERO;
+        }
+    }
 }
 
 // PlayerInput needs to be removed if Screen::Gameplay + (Event)Menu::Pause
@@ -117,7 +193,7 @@ fn add_player_input(mut commands: Commands, player: Single<Entity, With<Player>>
     commands.entity(*player).insert(PlayerInput);
 }
 
-fn remove_player_input(mut commands: Commands, player: Single<Entity, With<Player>>) {
+pub(crate) fn remove_player_input(mut commands: Commands, player: Single<Entity, With<Player>>) {
     commands
         .entity(*player)
         .remove_with_requires::<PlayerInput>()
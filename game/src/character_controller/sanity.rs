@@ -0,0 +1,100 @@
+//! The player's sanity and its HUD, see [`Sanity`]
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Sanity>()
+        .add_systems(OnEnter(Screen::Gameplay), spawn_sanity_hud)
+        .add_systems(
+            Update,
+            (
+                decay_sanity.in_set(PausableSystems),
+                update_sanity_hud.run_if(resource_changed::<Sanity>),
+            )
+                .run_if(in_state(Screen::Gameplay)),
+        );
+}
+
+/// Extra camera effect intensification at zero sanity, as a fraction of the normal one
+const MAX_INTENSIFICATION_BOOST: f32 = 2.;
+/// Opacity of the HUD bar at full and at zero sanity
+const HUD_ALPHA: (f32, f32) = (0.1, 0.5);
+
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+/// Player stat between 0 and 1 that slowly drains, lost sanity speeds up the growth of the
+/// camera status effects. Sanity zone chunks and drugs restore it, saved with the game scene.
+pub struct Sanity {
+    pub value: f32,
+    /// Sanity lost per second everywhere
+    pub decay_per_sec: f32,
+}
+
+impl Default for Sanity {
+    fn default() -> Self {
+        Self {
+            value: 1.,
+            decay_per_sec: 0.002,
+        }
+    }
+}
+
+impl Sanity {
+    /// Add `amount` to the sanity, negative values drain it
+    pub fn change(&mut self, amount: f32) {
+        self.value = (self.value + amount).clamp(0., 1.);
+    }
+
+    /// Factor applied to growing camera effect intensifications, 1 at full sanity
+    pub fn intensification_factor(&self) -> f32 {
+        1. + (1. - self.value) * MAX_INTENSIFICATION_BOOST
+    }
+}
+
+fn decay_sanity(time: Res<Time>, mut sanity: ResMut<Sanity>) {
+    let decay = sanity.decay_per_sec * time.delta_secs();
+    sanity.change(-decay);
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct SanityHudBar;
+
+fn hud_color(sanity: &Sanity) -> BackgroundColor {
+    let alpha = HUD_ALPHA.0 + (HUD_ALPHA.1 - HUD_ALPHA.0) * (1. - sanity.value);
+    BackgroundColor(Color::srgba(1., 1., 1., alpha))
+}
+
+fn spawn_sanity_hud(mut commands: Commands, sanity: Res<Sanity>) {
+    commands.spawn((
+        Name::new("Sanity HUD"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: percent(40),
+            bottom: px(12),
+            width: percent(20),
+            height: px(3),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1., 1., 1., 0.05)),
+        DespawnOnExit(Screen::Gameplay),
+        children![(
+            SanityHudBar,
+            Node {
+                width: percent(sanity.value * 100.),
+                height: percent(100),
+                ..default()
+            },
+            hud_color(&sanity),
+        )],
+    ));
+}
+
+fn update_sanity_hud(
+    sanity: Res<Sanity>,
+    mut bar: Single<(&mut Node, &mut BackgroundColor), With<SanityHudBar>>,
+) {
+    let (node, color) = &mut *bar;
+    node.width = percent(sanity.value * 100.);
+    **color = hud_color(&sanity);
+}
@@ -1,20 +1,42 @@
-use crate::character_controller::Player;
-use feverdream_trap_core::prelude::*;
-use std::mem::swap;
+use crate::camera_controller::CameraMarker;
+use crate::character_controller::{Player, PlayerMover, Sanity};
+use crate::interaction::OperateDoors;
+use crate::prelude::*;
+use rand::{Rng, SeedableRng, distr::weighted::WeightedIndex, rngs::StdRng};
 impl Plugin for ChunkPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ActivePlayerChunks>()
+            .init_resource::<RunSeed>()
+            .add_systems(OnEnter(Screen::Gameplay), reset_active_player_chunks)
             .add_observer(on_player_entered_chunk)
             .add_observer(on_player_exited_chunk)
             .add_observer(on_replace_chunk_asset)
+            .add_observer(on_revert_chunk_asset)
             .add_observer(on_swap_chunks)
             .add_observer(on_move_chunk)
+            .add_observer(on_activate_chunk)
+            .add_observer(on_deactivate_chunk)
+            .add_observer(hide_dormant_chunk)
+            .add_observer(show_activated_chunk)
             .add_systems(
                 PreUpdate,
                 (
                     swap_chunks_on_contact_with_sensor,
                     replace_chunk_asset_on_contact_with_sensor,
+                    revert_chunk_asset_on_contact_with_sensor,
                     move_chunk_on_contact_with_sensor,
+                    mutate_chunk_on_contact_with_sensor,
+                    toggle_dormancy_on_contact_with_sensor,
+                    operate_doors_on_contact_with_sensor,
+                    update_story_on_contact_with_sensor,
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    log_run_seed.run_if(resource_changed::<RunSeed>),
+                    teleport_player_across_chunks.in_set(PausableSystems),
+                    change_sanity_in_zone_chunks.in_set(PausableSystems),
                 ),
             );
     }
@@ -23,20 +45,43 @@ impl Plugin for ChunkPlugin {
 #[derive(Debug, Event)]
 pub struct SwapChunks(pub ChunkId, pub ChunkId);
 #[derive(Debug, Event)]
-pub struct ReplaceChunkAsset(pub ChunkId, pub Handle<ChunkDescriptor>);
+/// Replace the elements of a chunk in place, keeping the chunk entity and its markers
+pub struct ReplaceChunkAsset {
+    pub chunk: ChunkId,
+    pub descriptor: Handle<ChunkDescriptor>,
+    /// Markers replacing the chunk's current markers, `None` keeps them
+    pub markers: Option<Vec<ChunkMarker>>,
+    /// Whether the current descriptor should be pushed to the chunk's [`ChunkDescriptorHistory`]
+    pub record_history: bool,
+}
+#[derive(Debug, Event)]
+pub struct RevertChunkAsset(pub ChunkId, pub DescriptorRevert);
 #[derive(Debug, Event)]
 pub struct MoveChunk(pub ChunkId, pub i32, pub i32);
+#[derive(Debug, Event)]
+pub struct ActivateChunk(pub ChunkId);
+#[derive(Debug, Event)]
+pub struct DeactivateChunk(pub ChunkId);
 
 #[derive(Resource, Default, Reflect)]
 #[reflect(Resource)]
-/// Chunks the player is currently in
-struct ActivePlayerChunks(Vec<ActivePlayerChunk>);
+/// Chunks the player is currently in, reset for every gameplay session
+struct ActivePlayerChunks {
+    chunks: Vec<ActivePlayerChunk>,
+    /// Grid cell of the player when [`teleport_player_across_chunks`] last ran
+    last_cell: Option<IVec2>,
+}
 #[derive(Reflect)]
 struct ActivePlayerChunk {
     chunk_entity: Entity,
     swap_sensor_activated: bool,
     asset_sensor_activated: bool,
+    revert_sensor_activated: bool,
     move_sensor_activated: bool,
+    pool_sensor_activated: bool,
+    dormancy_sensor_activated: bool,
+    door_sensor_activated: bool,
+    story_sensor_activated: bool,
 }
 impl ActivePlayerChunk {
     fn new(id: Entity) -> Self {
@@ -44,8 +89,48 @@ impl ActivePlayerChunk {
             chunk_entity: id,
             swap_sensor_activated: false,
             asset_sensor_activated: false,
+            revert_sensor_activated: false,
             move_sensor_activated: false,
+            pool_sensor_activated: false,
+            dormancy_sensor_activated: false,
+            door_sensor_activated: false,
+            story_sensor_activated: false,
+        }
+    }
+}
+
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+#[reflect(Resource)]
+/// Seed of the current playthrough, rolled for every new run and saved with the game so chunk
+/// mutation pools roll the same way when a run is resumed. Set `FEVERDREAM_SEED` to reproduce a
+/// specific run.
+pub struct RunSeed(pub u64);
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(seed) = std::env::var("FEVERDREAM_SEED")
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+        {
+            return Self(seed);
         }
+        Self(rand::random())
+    }
+}
+
+impl RunSeed {
+    /// Deterministic rng for the random `stream` of a system, so the run stays reproducible from
+    /// its seed
+    pub fn rng(&self, stream: u64) -> StdRng {
+        StdRng::seed_from_u64(self.0.rotate_left(32) ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+
+    /// Deterministic rng for the `roll`-th trigger of the sensor chunk `chunk`, independent of the
+    /// order in which sensors are triggered
+    fn rng_for(&self, ChunkId(chunk): ChunkId, roll: u32) -> StdRng {
+        let salt = (((chunk as u64) << 32) | roll as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        StdRng::seed_from_u64(self.0 ^ salt)
     }
 }
 
@@ -70,7 +155,7 @@ fn on_player_entered_chunk(
 
     info!("Player {player} entered chunk {chunk_id}");
 
-    player_chunk.0.push(ActivePlayerChunk::new(chunk));
+    player_chunk.chunks.push(ActivePlayerChunk::new(chunk));
 }
 
 fn on_player_exited_chunk(
@@ -92,73 +177,114 @@ fn on_player_exited_chunk(
 
     info!("Player {player} exited chunk {chunk_id}");
 
-    player_chunk.0.retain(|active| active.chunk_entity != chunk);
+    player_chunk
+        .chunks
+        .retain(|active| active.chunk_entity != chunk);
 }
 
-fn on_swap_chunks(
-    event: On<SwapChunks>,
-    mut chunk_transform_query: Query<(&ChunkId, &mut Transform)>,
+fn on_swap_chunks(event: On<SwapChunks>, mut chunks: Chunks) {
+    let SwapChunks(chunk_a, chunk_b) = *event;
+
+    if chunks.swap(chunk_a, chunk_b) {
+        info!("Swapped chunk {:?} with {:?}", chunk_a.0, chunk_b.0);
+    }
+}
+
+fn on_replace_chunk_asset(
+    event: On<ReplaceChunkAsset>,
+    mut commands: Commands,
+    chunks: Res<ChunkIndex>,
+    descriptors: Res<Assets<ChunkDescriptor>>,
+    elements: Res<Assets<ChunkElement>>,
+    mut chunk_query: Query<(&mut Chunk, Option<&Children>)>,
+    element_query: Query<(), With<ChunkElementInstance>>,
 ) {
-    let SwapChunks(ChunkId(chunk_a), ChunkId(chunk_b)) = *event;
+    let ReplaceChunkAsset {
+        chunk: ChunkId(chunk_id),
+        ref descriptor,
+        ref markers,
+        record_history,
+    } = *event;
+
+    let Some((entity, (mut chunk, children))) = chunks
+        .entity(event.chunk)
+        .and_then(|entity| chunk_query.get_mut(entity).ok().map(|c| (entity, c)))
+    else {
+        return;
+    };
+    let Some(descriptor) = descriptors.get(descriptor) else {
+        warn!("Descriptor for chunk {chunk_id} is not loaded");
+        return;
+    };
 
-    let mut chunk_transforms =
-        chunk_transform_query
-            .iter_mut()
-            .filter_map(|(ChunkId(id), transform)| match id {
-                chunk_id if [chunk_a, chunk_b].contains(chunk_id) => Some(transform),
-                _ => None,
-            });
+    for child in children.into_iter().flatten() {
+        if element_query.contains(*child) {
+            commands.entity(*child).despawn();
+        }
+    }
+    spawn_chunk_elements(&mut commands, entity, descriptor, &elements);
 
-    let (chunk_a_transform, chunk_b_transform) = (chunk_transforms.next(), chunk_transforms.next());
+    let previous = std::mem::replace(&mut chunk.descriptor_name, descriptor.name.clone());
+    if record_history {
+        commands
+            .entity(entity)
+            .entry::<ChunkDescriptorHistory>()
+            .or_default()
+            .and_modify(move |mut history| history.0.push(previous));
+    }
 
-    if let (Some(mut transform_a), Some(mut transform_b)) = (chunk_a_transform, chunk_b_transform) {
-        swap(&mut transform_a.translation, &mut transform_b.translation);
-        info!("Swapped chunk {:?} with {:?}", chunk_a, chunk_b);
+    if let Some(markers) = markers.clone() {
+        commands.queue(move |world: &mut World| {
+            ChunkMarkers::clear(world, entity);
+            if !markers.is_empty() {
+                world.entity_mut(entity).insert(ChunkMarkers(markers));
+            }
+        });
     }
+
+    info!("Chunk {chunk_id} was replaced with {}", descriptor.name);
 }
 
-fn on_replace_chunk_asset(
-    event: On<ReplaceChunkAsset>,
+fn on_revert_chunk_asset(
+    event: On<RevertChunkAsset>,
     mut commands: Commands,
-    chunk_query: Query<(&ChunkId, &Transform, &ChildOf)>,
+    asset_server: Res<AssetServer>,
+    chunks: Res<ChunkIndex>,
+    mut history_query: Query<&mut ChunkDescriptorHistory>,
 ) {
-    let ChunkId(chunk_id) = event.0;
-    let chunk_asset = event.1.clone();
-
-    let Some((chunk_transform, ChildOf(level))) =
-        chunk_query
-            .iter()
-            .find_map(|(ChunkId(id), transform, child_of)| match *id {
-                n if n == chunk_id => Some((transform, child_of)),
-                _ => None,
-            })
+    let RevertChunkAsset(chunk_id, target) = *event;
+
+    let Some(name) = chunks
+        .entity(chunk_id)
+        .and_then(|entity| history_query.get_mut(entity).ok())
+        .and_then(|mut history| history.take(target))
     else {
+        info!("Chunk {} has no descriptor to revert to", chunk_id.0);
         return;
     };
 
-    commands.trigger(DespawnChunk(ChunkId(chunk_id)));
-    commands.trigger(SpawnChunk {
-        level: *level,
-        id: ChunkId(chunk_id),
-        grid_position: chunk_transform.translation.xz() / CHUNK_SIZE,
-        descriptor: chunk_asset.clone(),
-        components: vec![],
-    });
+    info!("Reverting chunk {} to {name}", chunk_id.0);
 
-    info!("Chunk {chunk_id} was replaced with {chunk_asset:?}");
+    commands.trigger(ReplaceChunkAsset {
+        chunk: chunk_id,
+        descriptor: asset_server.load(ChunkDescriptorAsset::path_from_name(&name)),
+        markers: None,
+        record_history: false,
+    });
 }
 
 fn replace_chunk_asset_on_contact_with_sensor(
     mut commands: Commands,
     mut player_chunk: ResMut<ActivePlayerChunks>,
-    mut chunk_query: Query<(&mut Chunk, &ChunkId)>,
+    chunks: Res<ChunkIndex>,
+    chunk_query: Query<&Chunk>,
     sensors_query: Query<(
         &ReplaceAssetSensorChunk,
         &ReplaceAssetSensorChunkHandle,
         &ChunkId,
     )>,
 ) {
-    for active in player_chunk.0.iter_mut() {
+    for active in player_chunk.chunks.iter_mut() {
         match active.asset_sensor_activated {
             true => continue,
             false => active.asset_sensor_activated = true,
@@ -170,28 +296,50 @@ fn replace_chunk_asset_on_contact_with_sensor(
 
         info!("Player triggered chunk asset replacement by entering sensor chunk {chunk_id}");
 
-        commands.trigger(ReplaceChunkAsset(sensor.chunk, sensor_handle.asset.clone()));
-        let mut chunk_cmds = commands.entity(active.chunk_entity);
-        match sensor.invert_after_swap {
-            true => {
-                let mut new_sensor = sensor.clone();
-                std::mem::swap(
-                    &mut chunk_query
-                        .iter_mut()
-                        .find(|(_, ChunkId(id))| *id == new_sensor.chunk.0)
-                        .unwrap()
-                        .0
-                        .descriptor_name,
-                    &mut new_sensor.descriptor,
-                );
-                // Trigger on_insert hook to update the asset handle on [`ReplaceAssetSensorChunkHandle`]
-                chunk_cmds.insert(new_sensor);
-            }
-            false => {
-                chunk_cmds
-                    .remove::<ReplaceAssetSensorChunk>()
-                    .remove::<ReplaceAssetSensorChunkHandle>();
-            }
+        let replaced = chunks
+            .entity(sensor.chunk)
+            .and_then(|entity| chunk_query.get(entity).ok())
+            .is_some_and(|chunk| chunk.descriptor_name == sensor.descriptor);
+        match sensor.invert_after_swap && replaced {
+            true => commands.trigger(RevertChunkAsset(sensor.chunk, DescriptorRevert::Previous)),
+            false => commands.trigger(ReplaceChunkAsset {
+                chunk: sensor.chunk,
+                descriptor: sensor_handle.asset.clone(),
+                markers: sensor.markers.clone(),
+                record_history: true,
+            }),
+        }
+
+        if !sensor.invert_after_swap {
+            commands
+                .entity(active.chunk_entity)
+                .remove::<ReplaceAssetSensorChunk>()
+                .remove::<ReplaceAssetSensorChunkHandle>();
+        }
+    }
+}
+
+fn revert_chunk_asset_on_contact_with_sensor(
+    mut commands: Commands,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    sensors_query: Query<(&RevertAssetSensorChunk, &ChunkId)>,
+) {
+    for active in player_chunk.chunks.iter_mut() {
+        match active.revert_sensor_activated {
+            true => continue,
+            false => active.revert_sensor_activated = true,
+        }
+        let Ok((sensor, ChunkId(chunk_id))) = sensors_query.get(active.chunk_entity) else {
+            continue;
+        };
+
+        info!("Player triggered chunk asset revert by entering sensor chunk {chunk_id}");
+
+        commands.trigger(RevertChunkAsset(sensor.chunk, sensor.target));
+        if !sensor.preserve_after_revert {
+            commands
+                .entity(active.chunk_entity)
+                .remove::<RevertAssetSensorChunk>();
         }
     }
 }
@@ -201,7 +349,7 @@ fn move_chunk_on_contact_with_sensor(
     mut player_chunk: ResMut<ActivePlayerChunks>,
     sensors_query: Query<(&MoveChunkSensorChunk, &ChunkId)>,
 ) {
-    for active in player_chunk.0.iter_mut() {
+    for active in player_chunk.chunks.iter_mut() {
         match active.move_sensor_activated {
             true => continue,
             false => active.move_sensor_activated = true,
@@ -219,28 +367,163 @@ fn move_chunk_on_contact_with_sensor(
     }
 }
 
-fn on_move_chunk(
-    event: On<MoveChunk>,
-    mut chunk_transform_query: Query<(&ChunkId, &mut Transform)>,
+fn on_move_chunk(event: On<MoveChunk>, mut chunks: Chunks) {
+    let MoveChunk(chunk_id, x, z) = *event;
+
+    if chunks.move_to(chunk_id, IVec2::new(x, z)) {
+        info!("Moved chunk {} to grid position ({x}, {z})", chunk_id.0);
+    }
+}
+
+fn on_activate_chunk(event: On<ActivateChunk>, mut commands: Commands, chunks: Res<ChunkIndex>) {
+    let ActivateChunk(chunk_id) = *event;
+
+    if let Some(entity) = chunks.entity(chunk_id) {
+        commands.entity(entity).try_remove::<Dormant>();
+        info!("Activated chunk {}", chunk_id.0);
+    }
+}
+
+fn on_deactivate_chunk(
+    event: On<DeactivateChunk>,
+    mut commands: Commands,
+    chunks: Res<ChunkIndex>,
 ) {
-    let MoveChunk(ChunkId(chunk_id), x, z) = *event;
-
-    for (ChunkId(id), mut transform) in &mut chunk_transform_query {
-        if *id == chunk_id {
-            transform.translation.x = x as f32 * CHUNK_SIZE;
-            transform.translation.z = z as f32 * CHUNK_SIZE;
-            info!("Moved chunk {chunk_id} to grid position ({x}, {z})");
-            return;
+    let DeactivateChunk(chunk_id) = *event;
+
+    if let Some(entity) = chunks.entity(chunk_id) {
+        commands.entity(entity).try_insert(Dormant);
+        info!("Deactivated chunk {}", chunk_id.0);
+    }
+}
+
+fn hide_dormant_chunk(event: On<Add, Dormant>, mut chunks: Query<&mut Visibility>) {
+    if let Ok(mut visibility) = chunks.get_mut(event.entity) {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+fn show_activated_chunk(event: On<Remove, Dormant>, mut chunks: Query<&mut Visibility>) {
+    if let Ok(mut visibility) = chunks.get_mut(event.entity) {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+fn toggle_dormancy_on_contact_with_sensor(
+    mut commands: Commands,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    sensors_query: Query<(&DormancySensorChunk, &ChunkId)>,
+) {
+    for active in player_chunk.chunks.iter_mut() {
+        match active.dormancy_sensor_activated {
+            true => continue,
+            false => active.dormancy_sensor_activated = true,
+        }
+        let Ok((sensor, ChunkId(chunk_id))) = sensors_query.get(active.chunk_entity) else {
+            continue;
+        };
+
+        info!("Player triggered chunk (de)activation by entering sensor chunk {chunk_id}");
+
+        for chunk in &sensor.deactivate {
+            commands.trigger(DeactivateChunk(*chunk));
+        }
+        for chunk in &sensor.activate {
+            commands.trigger(ActivateChunk(*chunk));
+        }
+        if !sensor.preserve_after_trigger {
+            commands
+                .entity(active.chunk_entity)
+                .remove::<DormancySensorChunk>();
+        }
+    }
+}
+
+fn operate_doors_on_contact_with_sensor(
+    mut commands: Commands,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    sensors_query: Query<(&DoorSensorChunk, &ChunkId)>,
+) {
+    for active in player_chunk.chunks.iter_mut() {
+        match active.door_sensor_activated {
+            true => continue,
+            false => active.door_sensor_activated = true,
+        }
+        let Ok((sensor, ChunkId(chunk_id))) = sensors_query.get(active.chunk_entity) else {
+            continue;
+        };
+
+        info!(
+            "Player triggered door {} by entering sensor chunk {chunk_id}",
+            sensor.door
+        );
+
+        commands.trigger(OperateDoors {
+            door: sensor.door,
+            action: sensor.action,
+        });
+        if !sensor.preserve_after_trigger {
+            commands
+                .entity(active.chunk_entity)
+                .remove::<DoorSensorChunk>();
         }
     }
 }
 
+fn update_story_on_contact_with_sensor(
+    mut commands: Commands,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    mut story: ResMut<StoryState>,
+    sensors_query: Query<(&StorySensorChunk, &ChunkId)>,
+) {
+    for active in player_chunk.chunks.iter_mut() {
+        match active.story_sensor_activated {
+            true => continue,
+            false => active.story_sensor_activated = true,
+        }
+        let Ok((sensor, ChunkId(chunk_id))) = sensors_query.get(active.chunk_entity) else {
+            continue;
+        };
+        if !story.check_all(&sensor.conditions) {
+            continue;
+        }
+
+        info!("Player updated the story state by entering sensor chunk {chunk_id}");
+
+        for effect in &sensor.effects {
+            story.apply(effect);
+        }
+        if !sensor.preserve_after_trigger {
+            commands
+                .entity(active.chunk_entity)
+                .remove::<StorySensorChunk>();
+        }
+    }
+}
+
+fn change_sanity_in_zone_chunks(
+    time: Res<Time>,
+    player_chunk: Res<ActivePlayerChunks>,
+    mut sanity: ResMut<Sanity>,
+    zones_query: Query<&SanityZoneChunk>,
+) {
+    let change: f32 = player_chunk
+        .chunks
+        .iter()
+        .filter_map(|active| zones_query.get(active.chunk_entity).ok())
+        .map(|zone| zone.change_per_sec)
+        .sum();
+    if change != 0. {
+        sanity.change(change * time.delta_secs());
+    }
+}
+
 fn swap_chunks_on_contact_with_sensor(
     mut commands: Commands,
     mut player_chunk: ResMut<ActivePlayerChunks>,
     sensors_query: Query<(&SwapSensorChunk, &ChunkId)>,
 ) {
-    for active in player_chunk.0.iter_mut() {
+    for active in player_chunk.chunks.iter_mut() {
         match active.swap_sensor_activated {
             true => continue,
             false => active.swap_sensor_activated = true,
@@ -267,3 +550,138 @@ fn swap_chunks_on_contact_with_sensor(
         }
     }
 }
+
+fn mutate_chunk_on_contact_with_sensor(
+    mut commands: Commands,
+    run_seed: Res<RunSeed>,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    sensors_query: Query<(
+        &MutationPoolSensorChunk,
+        &MutationPoolSensorChunkHandles,
+        &ChunkId,
+        Option<&MutationPoolRolls>,
+    )>,
+) {
+    for active in player_chunk.chunks.iter_mut() {
+        match active.pool_sensor_activated {
+            true => continue,
+            false => active.pool_sensor_activated = true,
+        }
+        let Ok((sensor, sensor_handles, sensor_id, rolls)) = sensors_query.get(active.chunk_entity)
+        else {
+            continue;
+        };
+
+        let Ok(weights) = WeightedIndex::new(sensor.pool.iter().map(|c| c.weight.max(0.))) else {
+            warn!(
+                "Mutation pool of sensor chunk {} has no valid candidates",
+                sensor_id.0
+            );
+            continue;
+        };
+        let roll = rolls.map_or(0, |MutationPoolRolls(roll)| *roll);
+        let index = run_seed.rng_for(*sensor_id, roll).sample(&weights);
+
+        info!(
+            "Player triggered chunk mutation by entering sensor chunk {}, rolled candidate {index}",
+            sensor_id.0
+        );
+
+        let handle = sensor_handles.assets.get(index).and_then(Option::as_ref);
+        match (&sensor.pool[index].mutation, handle) {
+            (ChunkMutation::Swap(other), _) => {
+                commands.trigger(SwapChunks(sensor.chunk, *other));
+            }
+            (ChunkMutation::ReplaceAsset(_), Some(handle)) => {
+                commands.trigger(ReplaceChunkAsset {
+                    chunk: sensor.chunk,
+                    descriptor: handle.clone(),
+                    markers: None,
+                    record_history: true,
+                });
+            }
+            (ChunkMutation::ReplaceAsset(descriptor), None) => {
+                warn!("Descriptor {descriptor} of mutation pool was not loaded");
+            }
+        }
+
+        let mut chunk_cmds = commands.entity(active.chunk_entity);
+        match sensor.preserve_after_mutation {
+            true => {
+                chunk_cmds.insert(MutationPoolRolls(roll + 1));
+            }
+            false => {
+                chunk_cmds
+                    .remove::<MutationPoolSensorChunk>()
+                    .remove::<MutationPoolSensorChunkHandles>();
+            }
+        }
+    }
+}
+
+fn reset_active_player_chunks(mut commands: Commands) {
+    commands.insert_resource(ActivePlayerChunks::default());
+}
+
+fn log_run_seed(run_seed: Res<RunSeed>) {
+    info!("Run seed: {}", run_seed.0);
+}
+
+/// Teleport the player once their center crosses into a [`TeleportSensorChunk`], keeping their
+/// offset within the chunk. Velocity and camera orientation are left untouched, so the transition
+/// is seamless when both chunks look alike.
+fn teleport_player_across_chunks(
+    mut player: PlayerMover,
+    mut player_chunk: ResMut<ActivePlayerChunks>,
+    chunks: Res<ChunkIndex>,
+    teleporters: Query<(&TeleportSensorChunk, &ChunkId), Without<Dormant>>,
+    chunk_transforms: Query<
+        &Transform,
+        (
+            With<Chunk>,
+            Without<Dormant>,
+            Without<Player>,
+            Without<CameraMarker>,
+        ),
+    >,
+) {
+    let Some(translation) = player.translation() else {
+        return;
+    };
+    let cell = grid_cell(translation);
+    if player_chunk.last_cell == Some(cell) {
+        return;
+    }
+    player_chunk.last_cell = Some(cell);
+
+    let Some((sensor, ChunkId(chunk_id), entity)) = chunks.at(cell).iter().find_map(|entity| {
+        teleporters
+            .get(*entity)
+            .ok()
+            .map(|(s, id)| (s, id, *entity))
+    }) else {
+        return;
+    };
+    let (Ok(source), Some(Ok(target))) = (
+        chunk_transforms.get(entity),
+        chunks
+            .entity(sensor.target)
+            .map(|e| chunk_transforms.get(e)),
+    ) else {
+        warn!(
+            "Teleport target {} of chunk {chunk_id} is missing or dormant",
+            sensor.target.0
+        );
+        return;
+    };
+
+    let delta = target.translation - source.translation;
+    player.shift(delta);
+    // Arriving in the target cell must not trigger a teleporter placed there
+    player_chunk.last_cell = Some(grid_cell(translation + delta));
+
+    info!(
+        "Teleported player from chunk {chunk_id} to chunk {}",
+        sensor.target.0
+    );
+}
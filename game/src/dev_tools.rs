@@ -2,7 +2,10 @@
 
 use std::any::TypeId;
 
-use crate::{camera_controller::CameraMarker, prelude::*};
+use crate::{
+    camera_controller::{CameraMarker, CameraStatusEffects, debug_panel},
+    prelude::*,
+};
 use bevy::{
     dev_tools::{
         fps_overlay::{FpsOverlayConfig, FpsOverlayPlugin, FrameTimeGraphConfig},
@@ -27,6 +30,7 @@ pub(super) fn plugin(app: &mut App) {
     .add_plugins(
         WorldInspectorPlugin::default().run_if(input_toggle_active(false, TOGGLE_INSPECTOR_KEY)),
     )
+    .add_plugins(debug_panel::plugin)
     .add_plugins(PhysicsDebugPlugin)
     .insert_gizmo_config(
         PhysicsGizmos {
@@ -42,6 +46,12 @@ pub(super) fn plugin(app: &mut App) {
     // Log `Screen` state transitions.
     app.add_systems(Update, log_transitions::<Screen>);
 
+    // Log story state changes, the state itself is listed in the inspector.
+    app.add_systems(
+        Update,
+        log_story_state.run_if(resource_changed::<StoryState>),
+    );
+
     // Toggle cursor grab for inspector
     app.add_systems(
         Update,
@@ -68,6 +78,24 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         toggle_physics_gizmos.run_if(input_just_pressed(TOGGLE_PHYSICS_GIZMOS_KEY)),
     );
+
+    app.add_systems(
+        EguiPrimaryContextPass,
+        debug_panel::status_effects_panel.run_if(
+            input_toggle_active(false, TOGGLE_STATUS_EFFECTS_PANEL_KEY)
+                .and(resource_exists::<CameraStatusEffects>),
+        ),
+    );
+
+    // Toggle cursor grab for the status effects panel
+    app.add_systems(
+        Update,
+        toggle_cursor_grab.run_if(input_just_pressed(TOGGLE_STATUS_EFFECTS_PANEL_KEY)),
+    );
+}
+
+fn log_story_state(story: Res<StoryState>) {
+    info!("Story state: {story:?}");
 }
 
 const TOGGLE_INSPECTOR_KEY: KeyCode = KeyCode::F1;
@@ -109,3 +137,5 @@ fn toggle_physics_gizmos(mut gizmo: ResMut<GizmoConfigStore>) {
         config.enabled = !config.enabled;
     }
 }
+
+const TOGGLE_STATUS_EFFECTS_PANEL_KEY: KeyCode = KeyCode::F6;
@@ -0,0 +1,139 @@
+//! Carrying, dropping and throwing props, see [`CarryInteraction`] and [`PropSocket`].
+
+use bevy_enhanced_input::prelude::{InputAction, Start};
+
+use crate::{
+    camera_controller::CameraMarker,
+    interaction::{AdvanceInteraction, Interactable},
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(throw_carried)
+        .add_observer(on_drop_carried)
+        .add_systems(Update, follow_camera.in_set(PausableSystems));
+}
+
+/// How fast carried props catch up with their hold position
+const CARRY_STIFFNESS: f32 = 15.;
+/// Carried props further than this from their hold position, e.g. stuck behind a wall, are dropped
+const CARRY_BREAK_DISTANCE: f32 = 2.5;
+
+/// Throws the carried prop, bound in the player's input context
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct ThrowAction;
+
+/// The prop currently carried by the player
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+#[require(GravityScale(0.))]
+pub struct Carried;
+
+/// Drop the carried prop, placing it into a nearby [`PropSocket`] unless it is thrown
+#[derive(Debug, Event)]
+pub struct DropCarried {
+    pub throw: bool,
+}
+
+/// A prop has been placed into the socket `entity`
+#[derive(Debug, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct PropSocketed {
+    pub entity: Entity,
+    pub prop: Entity,
+}
+
+fn throw_carried(
+    _: On<Start<ThrowAction>>,
+    mut commands: Commands,
+    carried: Query<(), With<Carried>>,
+) {
+    if !carried.is_empty() {
+        commands.trigger(DropCarried { throw: true });
+    }
+}
+
+fn on_drop_carried(
+    event: On<DropCarried>,
+    mut commands: Commands,
+    camera: Single<&GlobalTransform, With<CameraMarker>>,
+    mut carried: Query<
+        (
+            Entity,
+            &CarryInteraction,
+            &GlobalTransform,
+            Option<&ChildOf>,
+            &mut LinearVelocity,
+        ),
+        With<Carried>,
+    >,
+    sockets: Query<(Entity, &PropSocket, &GlobalTransform)>,
+    global_transforms: Query<&GlobalTransform>,
+) {
+    for (prop, carry, transform, child_of, mut velocity) in &mut carried {
+        commands.entity(prop).remove_with_requires::<Carried>();
+
+        if event.throw {
+            velocity.0 = camera.forward() * carry.throw_speed;
+            continue;
+        }
+
+        let position = transform.translation();
+        let Some((socket, prop_socket, socket_transform)) = sockets
+            .iter()
+            .find(|(_, s, t)| s.key == carry.key && t.translation().distance(position) <= s.radius)
+        else {
+            continue;
+        };
+
+        info!("Placed prop {prop} into socket {socket}");
+        if prop_socket.consume {
+            commands.entity(prop).despawn();
+        } else {
+            let parent_transform = child_of
+                .and_then(|child_of| global_transforms.get(child_of.parent()).ok())
+                .copied()
+                .unwrap_or_default();
+            commands
+                .entity(prop)
+                .remove::<(CarryInteraction, Interactable)>()
+                .insert((
+                    RigidBody::Static,
+                    socket_transform.reparented_to(&parent_transform),
+                ));
+        }
+        commands.entity(socket).remove::<PropSocket>();
+        commands.trigger(PropSocketed {
+            entity: socket,
+            prop,
+        });
+        commands.trigger(AdvanceInteraction { entity: socket });
+    }
+}
+
+fn follow_camera(
+    mut commands: Commands,
+    camera: Single<&GlobalTransform, With<CameraMarker>>,
+    mut carried: Query<
+        (
+            &CarryInteraction,
+            &GlobalTransform,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+        ),
+        With<Carried>,
+    >,
+) {
+    let camera = camera.compute_transform();
+    for (carry, transform, mut velocity, mut angular_velocity) in &mut carried {
+        let target = camera.translation + camera.forward() * carry.hold_distance;
+        let offset = target - transform.translation();
+        if offset.length() > CARRY_BREAK_DISTANCE {
+            commands.trigger(DropCarried { throw: false });
+            continue;
+        }
+        velocity.0 = offset * CARRY_STIFFNESS;
+        angular_velocity.0 = Vec3::ZERO;
+    }
+}
@@ -0,0 +1,136 @@
+//! Doors opening and closing with an animated transform, see [`DoorInteraction`].
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(on_operate_doors).add_systems(
+        Update,
+        (lock_door_prompts, animate_doors.in_set(PausableSystems)),
+    );
+}
+
+/// Operate all doors with the id `door`
+#[derive(Debug, Event)]
+pub struct OperateDoors {
+    pub door: u32,
+    pub action: DoorAction,
+}
+
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
+/// Animation state of a [`DoorInteraction`], inserted along with it
+pub struct DoorState {
+    /// Transform of the closed door
+    pub closed: Transform,
+    /// Whether the door is opening or open
+    pub open: bool,
+    /// Animation progress from closed (0) to open (1)
+    progress: f32,
+}
+
+impl DoorState {
+    pub fn new(closed: Transform) -> Self {
+        Self {
+            closed,
+            ..default()
+        }
+    }
+
+    fn transform(&self, door: &DoorInteraction) -> Transform {
+        let t = self.progress * self.progress * (3. - 2. * self.progress);
+        let offset = door.open_offset;
+        Transform {
+            translation: self.closed.translation + offset.translation * t,
+            rotation: Quat::IDENTITY.slerp(offset.rotation, t) * self.closed.rotation,
+            scale: self.closed.scale * Vec3::ONE.lerp(offset.scale, t),
+        }
+    }
+}
+
+/// Open or close `door`, playing its sound if the state changes
+pub(super) fn set_door_open(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    door: &DoorInteraction,
+    state: &mut DoorState,
+    open: bool,
+) {
+    if state.open == open {
+        return;
+    }
+    state.open = open;
+    let sound = match open {
+        true => &door.open_sound,
+        false => &door.close_sound,
+    };
+    play_door_sound(commands, asset_server, sound);
+}
+
+pub(super) fn play_door_sound(commands: &mut Commands, asset_server: &AssetServer, path: &str) {
+    if !path.is_empty() {
+        commands.spawn(sound_effect(asset_server.load(path.to_owned())));
+    }
+}
+
+fn on_operate_doors(
+    event: On<OperateDoors>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut doors: Query<(&mut DoorInteraction, &mut DoorState)>,
+) {
+    for (mut door, mut state) in &mut doors {
+        if door.id != event.door {
+            continue;
+        }
+        match event.action {
+            DoorAction::Toggle | DoorAction::Open if door.locked => {
+                play_door_sound(&mut commands, &asset_server, &door.locked_sound);
+            }
+            DoorAction::Toggle => {
+                let open = !state.open;
+                set_door_open(&mut commands, &asset_server, &door, &mut state, open);
+            }
+            DoorAction::Open => {
+                set_door_open(&mut commands, &asset_server, &door, &mut state, true);
+            }
+            DoorAction::Close => {
+                set_door_open(&mut commands, &asset_server, &door, &mut state, false);
+            }
+            DoorAction::Unlock => door.locked = false,
+            DoorAction::Lock => door.locked = true,
+        }
+    }
+}
+
+fn animate_doors(
+    time: Res<Time>,
+    mut doors: Query<(&DoorInteraction, &mut DoorState, &mut Transform)>,
+) {
+    for (door, mut state, mut transform) in &mut doors {
+        let target = if state.open { 1. } else { 0. };
+        if state.progress == target {
+            continue;
+        }
+        let step = time.delta_secs() / door.duration_secs.max(f32::EPSILON);
+        state.progress = if target > state.progress {
+            (state.progress + step).min(target)
+        } else {
+            (state.progress - step).max(target)
+        };
+        *transform = state.transform(door);
+    }
+}
+
+/// Show the prompt of locked doors as disabled
+fn lock_door_prompts(
+    mut commands: Commands,
+    doors: Query<(Entity, &DoorInteraction), Changed<DoorInteraction>>,
+) {
+    for (entity, door) in &doors {
+        if door.locked {
+            commands.entity(entity).insert(InteractionLocked);
+        } else {
+            commands.entity(entity).remove::<InteractionLocked>();
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! The elevator ride between levels, see [`ElevatorInteraction`].
+
+use crate::{
+    camera_controller::{CameraMarker, CameraShake},
+    character_controller::{Player, PlayerMover, remove_player_input},
+    interaction::OperateDoors,
+    prelude::*,
+    scene::GameSceneStorage,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(on_ride_elevator)
+        .add_observer(on_elevator_not_powered)
+        .add_observer(place_arriving_player)
+        .add_systems(
+            Update,
+            (
+                advance_elevator_ride
+                    .run_if(resource_exists::<ElevatorRide>)
+                    .in_set(PausableSystems),
+                flicker_lights.in_set(PausableSystems),
+            ),
+        )
+        .add_systems(OnExit(Screen::Gameplay), cancel_elevator_ride);
+}
+
+const CLOSING_DOORS_SECS: f32 = 1.;
+const RIDE_SECS: f32 = 3.;
+const ARRIVING_SECS: f32 = 1.5;
+/// Maximum camera offset while riding
+const SHAKE_AMPLITUDE: f32 = 0.03;
+const FLICKER_SECS: f32 = 0.6;
+const CALL_SOUND: &str = "audio/sound_effects/button_click.ogg";
+const NOT_POWERED_SOUND: &str = "audio/sound_effects/button_hover.ogg";
+
+/// Ride the elevator `entity` to the next level
+#[derive(Debug, EntityEvent)]
+pub struct RideElevator {
+    pub entity: Entity,
+}
+
+/// The elevator `entity` was called without being powered
+#[derive(Debug, EntityEvent)]
+pub struct ElevatorNotPowered {
+    pub entity: Entity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RidePhase {
+    ClosingDoors,
+    Riding,
+    /// Waiting for the next level to be spawned, then fading in
+    Arriving,
+}
+
+/// The elevator ride in progress, kept across the level transition
+#[derive(Debug, Resource)]
+pub struct ElevatorRide {
+    phase: RidePhase,
+    timer: Timer,
+    next_level: Option<GameLevel>,
+    doors: Option<u32>,
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct ElevatorFade;
+
+/// Flickers a light for [`FLICKER_SECS`], then restores its intensity
+#[derive(Debug, Component)]
+struct Flicker {
+    timer: Timer,
+    intensity: f32,
+}
+
+fn on_ride_elevator(
+    event: On<RideElevator>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    current_level: Res<CurrentLevel>,
+    doors: Query<&ElevatorDoors>,
+) {
+    let doors = doors.get(event.entity).ok().map(|doors| doors.0);
+    if let Some(door) = doors {
+        commands.trigger(OperateDoors {
+            door,
+            action: DoorAction::Close,
+        });
+    }
+    commands.spawn(sound_effect(asset_server.load(CALL_SOUND)));
+    commands.run_system_cached(remove_player_input);
+    commands.spawn((
+        Name::new("Elevator Fade"),
+        ElevatorFade,
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+        GlobalZIndex(1),
+        BackgroundColor(Color::BLACK.with_alpha(0.)),
+        Pickable::IGNORE,
+    ));
+    commands.insert_resource(ElevatorRide {
+        phase: RidePhase::ClosingDoors,
+        timer: Timer::from_seconds(CLOSING_DOORS_SECS, TimerMode::Once),
+        next_level: current_level.0.next(),
+        doors,
+    });
+}
+
+fn on_elevator_not_powered(
+    event: On<ElevatorNotPowered>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    parents: Query<&ChildOf>,
+    chunks: Query<(), With<Chunk>>,
+    children: Query<&Children>,
+    lights: Query<&PointLight, Without<Flicker>>,
+) {
+    commands.spawn(sound_effect(asset_server.load(NOT_POWERED_SOUND)));
+    // Flicker the lights of the elevator's chunk
+    let Some(chunk) = parents
+        .iter_ancestors(event.entity)
+        .find(|ancestor| chunks.contains(*ancestor))
+    else {
+        return;
+    };
+    for entity in children.iter_descendants(chunk) {
+        if let Ok(light) = lights.get(entity) {
+            commands.entity(entity).insert(Flicker {
+                timer: Timer::from_seconds(FLICKER_SECS, TimerMode::Once),
+                intensity: light.intensity,
+            });
+        }
+    }
+}
+
+fn flicker_lights(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut lights: Query<(Entity, &mut PointLight, &mut Flicker)>,
+) {
+    for (entity, mut light, mut flicker) in &mut lights {
+        flicker.timer.tick(time.delta());
+        if flicker.timer.is_finished() {
+            light.intensity = flicker.intensity;
+            commands.entity(entity).remove::<Flicker>();
+            continue;
+        }
+        let dim = rand::random::<bool>();
+        light.intensity = if dim {
+            flicker.intensity * 0.05
+        } else {
+            flicker.intensity
+        };
+    }
+}
+
+fn advance_elevator_ride(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut ride: ResMut<ElevatorRide>,
+    chunk_stash: Res<ChunkAssetStash>,
+    asset_server: Res<AssetServer>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut scene_storage: ResMut<GameSceneStorage>,
+    mut fade: Query<(Entity, &mut BackgroundColor), With<ElevatorFade>>,
+) {
+    // Only ticks in gameplay, so an arriving ride waits for the next level to be spawned, where the
+    // player is placed by `place_arriving_player`
+    ride.timer.tick(time.delta());
+    let progress = ride.timer.fraction();
+    let fade_alpha = match ride.phase {
+        RidePhase::ClosingDoors => 0.,
+        RidePhase::Riding => progress,
+        RidePhase::Arriving => 1. - progress,
+    };
+    for (_, mut background) in &mut fade {
+        background.0.set_alpha(fade_alpha);
+    }
+    if !ride.timer.is_finished() {
+        return;
+    }
+
+    match ride.phase {
+        RidePhase::ClosingDoors => {
+            ride.phase = RidePhase::Riding;
+            ride.timer = Timer::from_seconds(RIDE_SECS, TimerMode::Once);
+            commands.insert_resource(CameraShake {
+                amplitude: SHAKE_AMPLITUDE,
+            });
+        }
+        RidePhase::Riding => match ride.next_level {
+            Some(next) => {
+                // Every layout is loaded by the chunk asset stash, wait for the next one and its
+                // chunks so the transition doesn't stall on the loading screen
+                if !asset_server.is_loaded_with_dependencies(chunk_stash.layout(&next)) {
+                    return;
+                }
+                info!("Level complete! Loading next level: {next:?}");
+                current_level.0 = next;
+                // Clear saved scene so the next level spawns fresh
+                scene_storage.handle = None;
+                scene_storage.skip_save = true;
+                next_screen.set(Screen::Loading);
+                commands.remove_resource::<CameraShake>();
+                ride.phase = RidePhase::Arriving;
+                ride.timer = Timer::from_seconds(ARRIVING_SECS, TimerMode::Once);
+            }
+            None => {
+                info!("All levels complete!");
+                next_screen.set(Screen::GameOver);
+                commands.remove_resource::<CameraShake>();
+                for (entity, _) in &fade {
+                    commands.entity(entity).despawn();
+                }
+                commands.remove_resource::<ElevatorRide>();
+            }
+        },
+        RidePhase::Arriving => {
+            if let Some(door) = ride.doors {
+                commands.trigger(OperateDoors {
+                    door,
+                    action: DoorAction::Open,
+                });
+            }
+            for (entity, _) in &fade {
+                commands.entity(entity).despawn();
+            }
+            commands.remove_resource::<ElevatorRide>();
+        }
+    }
+}
+
+/// Quitting mid-ride leaves the elevator, only a ride arriving in the next level is kept
+fn cancel_elevator_ride(
+    mut commands: Commands,
+    ride: Option<Res<ElevatorRide>>,
+    fade: Query<Entity, With<ElevatorFade>>,
+) {
+    // The timer of an arriving ride only ticks once the next level is in gameplay
+    if ride.is_some_and(|ride| ride.phase == RidePhase::Arriving && ride.timer.elapsed().is_zero())
+    {
+        return;
+    }
+    for entity in &fade {
+        commands.entity(entity).despawn();
+    }
+    commands.remove_resource::<ElevatorRide>();
+    commands.remove_resource::<CameraShake>();
+}
+
+/// Place the player spawned in the next level inside its elevator chunk
+fn place_arriving_player(
+    _: On<Add, Player>,
+    ride: Option<Res<ElevatorRide>>,
+    arrival: Query<
+        &Transform,
+        (
+            With<ElevatorArrivalChunk>,
+            Without<Player>,
+            Without<CameraMarker>,
+        ),
+    >,
+    mut player: PlayerMover,
+) {
+    if ride.is_none_or(|ride| ride.phase != RidePhase::Arriving) {
+        return;
+    }
+    let Some(chunk_transform) = arrival.iter().next() else {
+        warn!("No elevator arrival chunk in the next level, keeping the player at the spawn point");
+        return;
+    };
+    player.place(chunk_transform.translation + Vec3::Y);
+}
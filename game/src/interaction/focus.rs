@@ -8,7 +8,7 @@ use bevy::{
 
 use crate::{
     camera_controller::CameraMarker,
-    interaction::{INTERACTION_DISTANCE, Interactable},
+    interaction::{Carried, Interactable, Inventory},
     prelude::*,
 };
 
@@ -24,36 +24,75 @@ pub(crate) fn plugin(app: &mut App) {
             Update,
             interactable_in_range.run_if(in_state(Screen::Gameplay).and(in_state(Menu::None))),
         )
-        .add_systems(Update, toggle_interact_ui_visibility)
+        .add_systems(
+            Update,
+            (toggle_interact_ui_visibility, update_interact_prompt),
+        )
         .add_observer(replace_standard_material);
 }
 
-// SpatialQuery ray cast from camera for interactable entities
+/// Radius of the sphere cast finding interactables the camera isn't aimed at exactly
+const FOCUS_TOLERANCE: f32 = 0.15;
+/// Interactables further away than this can't be focused, whatever their [`InteractionDistance`]
+const MAX_INTERACTION_DISTANCE: f32 = 20.;
+
+// SpatialQuery casts from camera for interactable entities
+// The interactable the camera is aimed at takes priority over the closest one within tolerance
 fn interactable_in_range(
     mut commands: Commands,
     spatial_query: SpatialQuery,
-    interactables: Query<&Interactable>,
+    interactables: Query<Option<&InteractionDistance>, With<Interactable>>,
     camera: Single<&GlobalTransform, With<CameraMarker>>,
     focus_targets: Query<Entity, With<FocusTarget>>,
+    carried: Query<Entity, With<Carried>>,
 ) {
     let transform = camera.compute_transform();
-    let hit = spatial_query.cast_ray_predicate(
-        transform.translation,
-        transform.forward(),
-        INTERACTION_DISTANCE,
-        true,
-        &SpatialQueryFilter::from_mask(GameLayer::Interactable),
-        &|_| true,
-    );
-
-    // TODO: This is not optimal
-    for entity in focus_targets {
-        commands.entity(entity).try_remove::<FocusTarget>();
+    // The carried prop would block the view on everything else
+    let filter = SpatialQueryFilter::from_mask(GameLayer::Interactable)
+        .with_excluded_entities(carried.iter());
+    let in_range = |entity: Entity, distance: f32| {
+        interactables.get(entity).is_ok_and(|interaction_distance| {
+            distance <= interaction_distance.map_or(INTERACTION_DISTANCE, |d| d.0)
+        })
+    };
+
+    let aimed_at = spatial_query
+        .cast_ray(
+            transform.translation,
+            transform.forward(),
+            MAX_INTERACTION_DISTANCE,
+            true,
+            &filter,
+        )
+        .filter(|hit| in_range(hit.entity, hit.distance))
+        .map(|hit| hit.entity);
+    let target = aimed_at.or_else(|| {
+        spatial_query
+            .shape_hits(
+                &Collider::sphere(FOCUS_TOLERANCE),
+                transform.translation,
+                Quat::IDENTITY,
+                transform.forward(),
+                4,
+                &ShapeCastConfig::from_max_distance(MAX_INTERACTION_DISTANCE),
+                &filter,
+            )
+            .into_iter()
+            .filter(|hit| in_range(hit.entity, hit.distance))
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+            .map(|hit| hit.entity)
+    });
+
+    // Only touch FocusTarget on transitions, its hooks walk the entity's hierarchy
+    for entity in &focus_targets {
+        if Some(entity) != target {
+            commands.entity(entity).try_remove::<FocusTarget>();
+        }
     }
-    if let Some(first_hit) = hit
-        && interactables.contains(first_hit.entity)
+    if let Some(target) = target
+        && !focus_targets.contains(target)
     {
-        commands.entity(first_hit.entity).try_insert(FocusTarget);
+        commands.entity(target).try_insert(FocusTarget);
     }
 }
 
@@ -92,14 +131,31 @@ impl FocusTarget {
     fn on_add(mut world: DeferredWorld, ctx: HookContext) {
         // Set MeshTag
         recursive_set_meshtag(&mut world, ctx.entity, HIGHLIGHT_MESH_TAG);
+        world.commands().trigger(FocusGained { entity: ctx.entity });
     }
 
     fn on_remove(mut world: DeferredWorld, ctx: HookContext) {
         // Reset MeshTag
         recursive_set_meshtag(&mut world, ctx.entity, DEFAULT_MESH_TAG);
+        // Also triggered when the focused entity is despawned
+        world.commands().trigger(FocusLost { entity: ctx.entity });
     }
 }
 
+/// An interactable became the [`FocusTarget`]
+#[derive(Debug, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct FocusGained {
+    pub entity: Entity,
+}
+
+/// An interactable stopped being the [`FocusTarget`]
+#[derive(Debug, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct FocusLost {
+    pub entity: Entity,
+}
+
 const SHADER_ASSET_PATH: &str = "shaders/blend.wgsl";
 
 // TODO: This cant be empty?
@@ -155,6 +211,13 @@ fn replace_standard_material(
 #[reflect(Component)]
 struct InteractUiMarker;
 
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct InteractPromptText;
+
+const PROMPT_COLOR: Color = Color::srgba(1., 1., 1., 0.8);
+const DISABLED_PROMPT_COLOR: Color = Color::srgba(0.6, 0.6, 0.6, 0.8);
+
 fn setup_interact_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn((
         Name::new("InteractUiMarker"),
@@ -168,23 +231,31 @@ fn setup_interact_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
             ..default()
         },
         Visibility::Hidden,
-        children![(
-            ImageNode {
-                color: Color::Srgba(Srgba {
-                    red: 1.0,
-                    green: 1.0,
-                    blue: 1.0,
-                    alpha: 0.5
-                }),
-                image: asset_server.load("images/interact.png"),
-                ..default()
-            },
-            Node {
-                width: percent(10),
-                height: percent(10),
-                ..default()
-            },
-        )],
+        children![
+            (
+                ImageNode {
+                    color: Color::Srgba(Srgba {
+                        red: 1.0,
+                        green: 1.0,
+                        blue: 1.0,
+                        alpha: 0.5
+                    }),
+                    image: asset_server.load("images/interact.png"),
+                    ..default()
+                },
+                Node {
+                    width: percent(10),
+                    height: percent(10),
+                    ..default()
+                },
+            ),
+            (
+                InteractPromptText,
+                Text::default(),
+                TextFont::from_font_size(24.),
+                TextColor(PROMPT_COLOR),
+            ),
+        ],
     ));
 }
 
@@ -200,3 +271,47 @@ fn toggle_interact_ui_visibility(
         }
     }
 }
+
+fn update_interact_prompt(
+    inventory: Res<Inventory>,
+    story: Res<StoryState>,
+    focus_target: Query<
+        (
+            Option<&InteractionPrompt>,
+            Option<&RequiresItem>,
+            Has<InteractionLocked>,
+            Option<&InteractionStages>,
+            Option<&StoryConditions>,
+        ),
+        With<FocusTarget>,
+    >,
+    mut prompt: Single<(&mut Text, &mut TextColor), With<InteractPromptText>>,
+) {
+    let Some((interaction_prompt, requirement, locked, stages, conditions)) =
+        focus_target.iter().next()
+    else {
+        return;
+    };
+    // Stages left to complete take over the prompt and requirement
+    let (interaction_prompt, requirement) = match stages.and_then(InteractionStages::current) {
+        Some(stage) => (stage.prompt.clone(), stage.requires.as_ref()),
+        None => (interaction_prompt.cloned().unwrap_or_default(), requirement),
+    };
+    let locked =
+        locked || conditions.is_some_and(|conditions| !story.check_all(&conditions.conditions));
+    let (text, color) = if locked || !inventory.fulfils(requirement) {
+        let text = match (interaction_prompt.disabled.is_empty(), requirement) {
+            (false, _) => interaction_prompt.disabled,
+            (true, Some(RequiresItem { item, count, .. })) if !locked => {
+                format!("Requires {count} {item:?}")
+            }
+            (true, _) => "Unavailable".to_owned(),
+        };
+        (text, DISABLED_PROMPT_COLOR)
+    } else {
+        (interaction_prompt.text(), PROMPT_COLOR)
+    };
+    let (prompt_text, prompt_color) = &mut *prompt;
+    prompt_text.0 = text;
+    prompt_color.0 = color;
+}
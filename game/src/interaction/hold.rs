@@ -0,0 +1,153 @@
+//! Hold-to-interact, see [`HoldToInteract`].
+
+use bevy_enhanced_input::prelude::Complete;
+
+use crate::{
+    interaction::{
+        AdvanceInteraction, InteractAction,
+        focus::{FocusLost, FocusTarget},
+    },
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<InteractHold>()
+        .add_observer(release_interact_hold)
+        .add_observer(cancel_interact_hold)
+        .add_systems(Startup, setup_hold_ui)
+        // The interact input is removed with the menus open, so it is never released
+        .add_systems(OnExit(Menu::None), cancel_hold_on_menu)
+        .add_systems(
+            Update,
+            (
+                advance_interact_hold.in_set(PausableSystems),
+                update_hold_ui,
+            )
+                .chain(),
+        );
+}
+
+/// The interaction currently being held
+#[derive(Debug, Default, Resource, Reflect)]
+#[reflect(Resource)]
+pub(super) struct InteractHold {
+    target: Option<Entity>,
+    elapsed_secs: f32,
+}
+
+impl InteractHold {
+    pub(super) fn start(&mut self, target: Entity) {
+        self.target = Some(target);
+        self.elapsed_secs = 0.;
+    }
+
+    fn cancel(&mut self) {
+        self.target = None;
+    }
+}
+
+/// How long the interact input has to be held for the next interaction with an entity
+pub(super) fn hold_duration(
+    hold_to_interact: Option<&HoldToInteract>,
+    stages: Option<&InteractionStages>,
+) -> f32 {
+    match stages.and_then(InteractionStages::current) {
+        Some(stage) => stage.hold_secs,
+        None => hold_to_interact.map_or(0., |hold| hold.duration_secs),
+    }
+}
+
+fn release_interact_hold(_: On<Complete<InteractAction>>, mut hold: ResMut<InteractHold>) {
+    hold.cancel();
+}
+
+fn cancel_interact_hold(event: On<FocusLost>, mut hold: ResMut<InteractHold>) {
+    if hold.target == Some(event.entity) {
+        hold.cancel();
+    }
+}
+
+fn cancel_hold_on_menu(mut hold: ResMut<InteractHold>) {
+    hold.cancel();
+}
+
+fn advance_interact_hold(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut hold: ResMut<InteractHold>,
+    focus_targets: Query<(Option<&HoldToInteract>, Option<&InteractionStages>), With<FocusTarget>>,
+) {
+    let Some(target) = hold.target else {
+        return;
+    };
+    let Ok((hold_to_interact, stages)) = focus_targets.get(target) else {
+        hold.cancel();
+        return;
+    };
+    hold.elapsed_secs += time.delta_secs();
+    if hold.elapsed_secs >= hold_duration(hold_to_interact, stages) {
+        hold.cancel();
+        commands.trigger(AdvanceInteraction { entity: target });
+    }
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct HoldProgressUi;
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct HoldProgressFill;
+
+fn setup_hold_ui(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Hold Progress"),
+        HoldProgressUi,
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            bottom: percent(35),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Visibility::Hidden,
+        children![(
+            Node {
+                width: px(160),
+                height: px(6),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1., 1., 1., 0.2)),
+            children![(
+                HoldProgressFill,
+                Node {
+                    width: percent(0),
+                    height: percent(100),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(1., 1., 1., 0.8)),
+            )],
+        )],
+    ));
+}
+
+fn update_hold_ui(
+    hold: Res<InteractHold>,
+    focus_targets: Query<(Option<&HoldToInteract>, Option<&InteractionStages>), With<FocusTarget>>,
+    mut ui: Single<&mut Visibility, With<HoldProgressUi>>,
+    mut fill: Single<&mut Node, With<HoldProgressFill>>,
+) {
+    let progress = hold
+        .target
+        .and_then(|target| focus_targets.get(target).ok())
+        .map(|(hold_to_interact, stages)| {
+            hold.elapsed_secs / hold_duration(hold_to_interact, stages).max(f32::EPSILON)
+        });
+    match progress {
+        Some(progress) => {
+            **ui = Visibility::Inherited;
+            fill.width = percent(progress.min(1.) * 100.);
+        }
+        None => **ui = Visibility::Hidden,
+    }
+}
@@ -2,9 +2,13 @@ use bevy::ecs::{lifecycle::HookContext, world::DeferredWorld};
 
 use crate::{
     chunk::SwapChunks,
-    interaction::{Fuse, Interact, Interactable},
+    interaction::{
+        Carried, Interact, Interactable, Inventory, ItemRequirementNotMet,
+        door::{DoorState, play_door_sound, set_door_open},
+        elevator::{ElevatorNotPowered, ElevatorRide, RideElevator},
+    },
+    menus::ReadNote,
     prelude::*,
-    scene::GameSceneStorage,
 };
 
 pub(super) fn register_required_components(world: &mut World) {
@@ -12,8 +16,13 @@ pub(super) fn register_required_components(world: &mut World) {
     world.register_required_components::<DespawnInteraction, Interactable>();
     world.register_required_components::<SwapChunksInteraction, Interactable>();
     world.register_required_components::<PlaySoundEffectInteraction, Interactable>();
-    world.register_required_components::<PickupFuseInteraction, Interactable>();
+    world.register_required_components::<PickupInteraction, Interactable>();
     world.register_required_components::<ElevatorInteraction, Interactable>();
+    world.register_required_components::<DoorInteraction, Interactable>();
+    world.register_required_components::<InteractionStages, Interactable>();
+    world.register_required_components::<CarryInteraction, Interactable>();
+    world.register_required_components::<StoryInteraction, Interactable>();
+    world.register_required_components::<ReadNoteInteraction, Interactable>();
 }
 
 pub(super) fn register_component_hooks(world: &mut World) {
@@ -89,13 +98,21 @@ pub(super) fn register_component_hooks(world: &mut World) {
         });
 
     world
-        .register_component_hooks::<PickupFuseInteraction>()
+        .register_component_hooks::<PickupInteraction>()
         .on_add(|mut world: DeferredWorld, ctx: HookContext| {
             world.commands().spawn(
-                Observer::new(|_on_interact: On<Interact>, mut fuse: ResMut<Fuse>| {
-                    info!("Picked up fuse");
-                    fuse.0 = true;
-                })
+                Observer::new(
+                    |on_interact: On<Interact>,
+                     mut inventory: ResMut<Inventory>,
+                     pickup_interaction: Query<&PickupInteraction>| {
+                        if let Ok(PickupInteraction { item, count }) =
+                            pickup_interaction.get(on_interact.entity)
+                        {
+                            info!("Picked up {count} {item:?}");
+                            inventory.add(*item, *count);
+                        }
+                    },
+                )
                 .with_entity(ctx.entity),
             );
         });
@@ -105,29 +122,142 @@ pub(super) fn register_component_hooks(world: &mut World) {
         .on_add(|mut world: DeferredWorld, ctx: HookContext| {
             world.commands().spawn(
                 Observer::new(
-                    |_on_interact: On<Interact>,
-                     mut fuse: ResMut<Fuse>,
-                     mut current_level: ResMut<CurrentLevel>,
-                     mut next_screen: ResMut<NextState<Screen>>,
-                     mut scene_storage: ResMut<GameSceneStorage>| {
-                        if fuse.0 {
-                            match current_level.0.next() {
-                                Some(next) => {
-                                    info!("Level complete! Loading next level: {next:?}");
-                                    current_level.0 = next;
-                                    fuse.0 = false;
-                                    // Clear saved scene so the next level spawns fresh
-                                    scene_storage.handle = None;
-                                    scene_storage.skip_save = true;
-                                    next_screen.set(Screen::Loading);
-                                }
-                                None => {
-                                    info!("All levels complete!");
-                                    next_screen.set(Screen::GameOver);
-                                }
+                    |on_interact: On<Interact>,
+                     mut commands: Commands,
+                     ride: Option<Res<ElevatorRide>>| {
+                        if ride.is_none() {
+                            commands.trigger(RideElevator {
+                                entity: on_interact.entity,
+                            });
+                        }
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+            // The elevator requires a fuse, see [`ElevatorInteraction`]
+            world.commands().spawn(
+                Observer::new(
+                    |not_met: On<ItemRequirementNotMet>, mut commands: Commands| {
+                        commands.trigger(ElevatorNotPowered {
+                            entity: not_met.entity,
+                        });
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+        });
+
+    world.register_component_hooks::<DoorInteraction>().on_add(
+        |mut world: DeferredWorld, ctx: HookContext| {
+            let closed = world
+                .get::<Transform>(ctx.entity)
+                .copied()
+                .unwrap_or_default();
+            // Doors are moved by `animate_doors`, which static bodies don't support
+            world
+                .commands()
+                .entity(ctx.entity)
+                .insert_if_new(DoorState::new(closed))
+                .insert(RigidBody::Kinematic);
+            world.commands().spawn(
+                Observer::new(
+                    |on_interact: On<Interact>,
+                     mut commands: Commands,
+                     asset_server: Res<AssetServer>,
+                     mut doors: Query<(
+                        &DoorInteraction,
+                        &mut DoorState,
+                        Option<&RequiresItem>,
+                    )>| {
+                        let Ok((door, mut state, requirement)) = doors.get_mut(on_interact.entity)
+                        else {
+                            return;
+                        };
+                        if door.locked {
+                            info!("Door {} is locked", door.id);
+                            play_door_sound(&mut commands, &asset_server, &door.locked_sound);
+                            return;
+                        }
+                        // Once unlocked with an item, the door stays unlocked
+                        if requirement.is_some() {
+                            commands.entity(on_interact.entity).remove::<RequiresItem>();
+                        }
+                        let open = !state.open;
+                        set_door_open(&mut commands, &asset_server, door, &mut state, open);
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+            world.commands().spawn(
+                Observer::new(
+                    |not_met: On<ItemRequirementNotMet>,
+                     mut commands: Commands,
+                     asset_server: Res<AssetServer>,
+                     doors: Query<&DoorInteraction>| {
+                        if let Ok(door) = doors.get(not_met.entity) {
+                            info!("Door {} is locked", door.id);
+                            play_door_sound(&mut commands, &asset_server, &door.locked_sound);
+                        }
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+        },
+    );
+
+    world.register_component_hooks::<CarryInteraction>().on_add(
+        |mut world: DeferredWorld, ctx: HookContext| {
+            world.commands().spawn(
+                Observer::new(
+                    |on_interact: On<Interact>,
+                     mut commands: Commands,
+                     carried: Query<(), With<Carried>>| {
+                        if carried.is_empty() {
+                            commands.entity(on_interact.entity).insert(Carried);
+                        }
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+        },
+    );
+
+    world.register_component_hooks::<StoryInteraction>().on_add(
+        |mut world: DeferredWorld, ctx: HookContext| {
+            world.commands().spawn(
+                Observer::new(
+                    |on_interact: On<Interact>,
+                     mut story: ResMut<StoryState>,
+                     story_interaction: Query<&StoryInteraction>| {
+                        if let Ok(StoryInteraction { effects }) =
+                            story_interaction.get(on_interact.entity)
+                        {
+                            for effect in effects {
+                                story.apply(effect);
                             }
-                        } else {
-                            info!("NO");
+                        }
+                    },
+                )
+                .with_entity(ctx.entity),
+            );
+        },
+    );
+
+    world
+        .register_component_hooks::<ReadNoteInteraction>()
+        .on_add(|mut world: DeferredWorld, ctx: HookContext| {
+            world.commands().spawn(
+                Observer::new(
+                    |on_interact: On<Interact>,
+                     mut commands: Commands,
+                     read_note_interaction: Query<&ReadNoteInteraction>| {
+                        if let Ok(ReadNoteInteraction(note)) =
+                            read_note_interaction.get(on_interact.entity)
+                        {
+                            commands.trigger(ReadNote {
+                                note: note.clone(),
+                                return_to: Menu::None,
+                            });
                         }
                     },
                 )
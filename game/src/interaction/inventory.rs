@@ -0,0 +1,128 @@
+//! The player's inventory and its HUD.
+
+use crate::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Inventory>()
+        .add_systems(OnEnter(Screen::Gameplay), spawn_inventory_hud)
+        .add_systems(
+            Update,
+            update_inventory_hud
+                .run_if(in_state(Screen::Gameplay).and(resource_changed::<Inventory>)),
+        );
+}
+
+#[derive(Debug, Default, Resource, Reflect)]
+#[reflect(Resource)]
+/// Items held by the player, saved with the game scene
+pub struct Inventory {
+    items: HashMap<Item, u32>,
+}
+
+impl Inventory {
+    pub fn count(&self, item: Item) -> u32 {
+        self.items.get(&item).copied().unwrap_or_default()
+    }
+
+    pub fn has(&self, item: Item, count: u32) -> bool {
+        self.count(item) >= count
+    }
+
+    pub fn add(&mut self, item: Item, count: u32) {
+        *self.items.entry(item).or_default() += count;
+    }
+
+    /// Remove `count` of `item`, returns false without removing anything if there are not enough
+    pub fn remove(&mut self, item: Item, count: u32) -> bool {
+        if !self.has(item, count) {
+            return false;
+        }
+        let held = self.items.get_mut(&item).unwrap();
+        *held -= count;
+        if *held == 0 {
+            self.items.remove(&item);
+        }
+        true
+    }
+
+    /// Whether `requirement` is met, without consuming anything
+    pub fn fulfils(&self, requirement: Option<&RequiresItem>) -> bool {
+        requirement.is_none_or(|requirement| self.has(requirement.item, requirement.count))
+    }
+
+    /// Whether `first` is met and `second` still is once `first` consumed its items
+    pub fn fulfils_after(
+        &self,
+        first: Option<&RequiresItem>,
+        second: Option<&RequiresItem>,
+    ) -> bool {
+        let Some(first) = first else {
+            return self.fulfils(second);
+        };
+        self.fulfils(Some(first))
+            && second.is_none_or(|second| {
+                let consumed = if first.consume && first.item == second.item {
+                    first.count
+                } else {
+                    0
+                };
+                self.has(second.item, second.count + consumed)
+            })
+    }
+
+    /// Check `requirement` and consume its items if it is met and consumable
+    ///
+    /// Interactions gated behind a [`RequiresItem`] call this before doing anything.
+    pub fn try_fulfil(&mut self, requirement: Option<&RequiresItem>) -> bool {
+        let Some(requirement) = requirement else {
+            return true;
+        };
+        if !self.fulfils(Some(requirement)) {
+            return false;
+        }
+        if requirement.consume {
+            self.remove(requirement.item, requirement.count);
+        }
+        true
+    }
+
+    fn hud_text(&self) -> String {
+        let mut items: Vec<_> = self.items.iter().collect();
+        items.sort_by_key(|(item, _)| format!("{item:?}"));
+        items
+            .into_iter()
+            .map(|(item, count)| format!("{item:?} x{count}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct InventoryHudText;
+
+fn spawn_inventory_hud(mut commands: Commands, inventory: Res<Inventory>) {
+    commands.spawn((
+        Name::new("Inventory HUD"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(20),
+            bottom: px(20),
+            ..default()
+        },
+        DespawnOnExit(Screen::Gameplay),
+        children![(
+            InventoryHudText,
+            Text::new(inventory.hud_text()),
+            TextFont::from_font_size(20.),
+            TextColor(Color::srgba(1., 1., 1., 0.6)),
+        )],
+    ));
+}
+
+fn update_inventory_hud(
+    inventory: Res<Inventory>,
+    mut text: Single<&mut Text, With<InventoryHudText>>,
+) {
+    text.0 = inventory.hud_text();
+}
@@ -1,7 +1,13 @@
+mod carry;
+mod door;
+mod elevator;
 mod focus;
+mod hold;
 mod interactions;
+mod inventory;
 
 use bevy::ecs::{lifecycle::HookContext, world::DeferredWorld};
+use bevy_enhanced_input::prelude::{InputAction, Start};
 
 use crate::{
     interaction::{
@@ -11,19 +17,26 @@ use crate::{
     prelude::*,
 };
 
-const INTERACTION_DISTANCE: f32 = 5.0;
+pub use carry::{Carried, DropCarried, PropSocketed, ThrowAction};
+pub use door::{DoorState, OperateDoors};
+pub use elevator::ElevatorRide;
+pub use inventory::Inventory;
 
 pub(crate) fn plugin(app: &mut App) {
-    app.init_resource::<Fuse>()
-        .add_plugins(focus::plugin)
-        .add_systems(
-            Startup,
-            (register_required_components, register_component_hooks),
-        )
-        .add_systems(
-            Update,
-            interact.run_if(in_state(Screen::Gameplay).and(in_state(Menu::None))),
-        );
+    app.add_plugins((
+        carry::plugin,
+        door::plugin,
+        elevator::plugin,
+        focus::plugin,
+        hold::plugin,
+        inventory::plugin,
+    ))
+    .add_systems(
+        Startup,
+        (register_required_components, register_component_hooks),
+    )
+    .add_observer(interact)
+    .add_observer(advance_interaction);
 }
 
 /// Indicates whether an entity can be interacted with
@@ -36,8 +49,17 @@ impl Interactable {
     fn on_add(mut world: DeferredWorld, ctx: HookContext) {
         info!("On add interactable {:?}", ctx.entity);
         // This is used for finding focus target; see focus
+        // Doors also have to block the player, so they stay on the default layer
+        let layers = if world.entity(ctx.entity).contains::<DoorInteraction>() {
+            CollisionLayers::new(
+                [GameLayer::Default, GameLayer::Interactable],
+                LayerMask::ALL,
+            )
+        } else {
+            CollisionLayers::new(GameLayer::Interactable, LayerMask::ALL)
+        };
         world.commands().entity(ctx.entity).insert((
-            CollisionLayers::new(GameLayer::Interactable, LayerMask::ALL),
+            layers,
             #[cfg(feature = "dev")]
             DebugInteraction,
         ));
@@ -56,18 +78,114 @@ impl From<Entity> for Interact {
     }
 }
 
+/// Interacts with the focus target, bound in the player's input context
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+pub struct InteractAction;
+
+/// Attempt to interact with an entity, completing its current [`InteractionStage`] if it has
+/// [`InteractionStages`] left and triggering [`Interact`] otherwise
+#[derive(Debug, EntityEvent)]
+pub struct AdvanceInteraction {
+    pub entity: Entity,
+}
+
+/// The player lacks the items of an entity's [`RequiresItem`], so its interactions didn't fire
+#[derive(Debug, EntityEvent)]
+pub struct ItemRequirementNotMet {
+    pub entity: Entity,
+}
+
+/// A stage of an entity's [`InteractionStages`] has been completed
+#[derive(Debug, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct InteractionStageCompleted {
+    pub entity: Entity,
+    pub stage: usize,
+}
+
 fn interact(
+    _: On<Start<InteractAction>>,
     mut commands: Commands,
-    mouse: Res<ButtonInput<MouseButton>>,
-    focus_targets: Query<Entity, With<FocusTarget>>,
+    screen: Res<State<Screen>>,
+    menu: Res<State<Menu>>,
+    mut hold: ResMut<hold::InteractHold>,
+    focus_targets: Query<
+        (Entity, Option<&HoldToInteract>, Option<&InteractionStages>),
+        With<FocusTarget>,
+    >,
+    carried: Query<(), With<Carried>>,
 ) {
-    for entity in &focus_targets {
-        if mouse.just_pressed(MouseButton::Left) {
-            commands.entity(entity).trigger(Interact::from);
+    // Observers can't have run conditions, so check the states they would have gated on
+    if *screen.get() != Screen::Gameplay || *menu.get() != Menu::None {
+        return;
+    }
+    // Interacting while carrying a prop drops it
+    if !carried.is_empty() {
+        commands.trigger(DropCarried { throw: false });
+        return;
+    }
+    for (entity, hold_to_interact, stages) in &focus_targets {
+        if hold::hold_duration(hold_to_interact, stages) > 0. {
+            hold.start(entity);
+        } else {
+            commands.trigger(AdvanceInteraction { entity });
         }
     }
 }
 
-#[derive(Debug, Default, Resource, Reflect)]
-#[reflect(Resource)]
-pub struct Fuse(pub bool);
+fn advance_interaction(
+    event: On<AdvanceInteraction>,
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    story: Res<StoryState>,
+    conditions: Query<&StoryConditions>,
+    requirements: Query<&RequiresItem>,
+    mut stages: Query<&mut InteractionStages>,
+) {
+    let entity = event.entity;
+    if let Ok(conditions) = conditions.get(entity)
+        && !story.check_all(&conditions.conditions)
+    {
+        info!("Story conditions of {entity} not met");
+        return;
+    }
+    // Checked before any stage so a missing item doesn't use up the stages
+    let requirement = requirements.get(entity).ok();
+    if !inventory.fulfils(requirement) {
+        info!("Item requirement of {entity} not met");
+        commands.trigger(ItemRequirementNotMet { entity });
+        return;
+    }
+    if let Ok(mut stages) = stages.get_mut(entity)
+        && let Some(stage) = stages.current()
+    {
+        if !inventory.fulfils(stage.requires.as_ref()) {
+            info!(
+                "Interaction stage {} of {entity} not fulfilled",
+                stages.current
+            );
+            return;
+        }
+        // The last stage must leave enough items for the entity's own requirement
+        let is_last = stages.current + 1 == stages.stages.len();
+        if is_last && !inventory.fulfils_after(stage.requires.as_ref(), requirement) {
+            info!("Item requirement of {entity} not met after its last stage");
+            commands.trigger(ItemRequirementNotMet { entity });
+            return;
+        }
+        inventory.try_fulfil(stage.requires.as_ref());
+        commands.trigger(InteractionStageCompleted {
+            entity,
+            stage: stages.current,
+        });
+        stages.current += 1;
+        // The entity's interactions only fire once the last stage is completed
+        if stages.current < stages.stages.len() {
+            return;
+        }
+    }
+    // Already checked together with the last stage
+    inventory.try_fulfil(requirement);
+    commands.entity(entity).trigger(Interact::from);
+}
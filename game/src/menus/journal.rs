@@ -0,0 +1,61 @@
+//! The journal, listing the notes read so far. Opened from the pause menu.
+
+use crate::{menus::ReadNote, prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Journal>()
+        .add_systems(OnEnter(Menu::Journal), spawn_journal_menu)
+        .add_systems(
+            Update,
+            go_back.run_if(in_state(Menu::Journal).and(input_just_pressed(KeyCode::Escape))),
+        );
+}
+
+/// Notes read by the player, saved with the game scene
+#[derive(Debug, Default, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct Journal {
+    notes: Vec<Note>,
+}
+
+impl Journal {
+    /// Record `note`, notes are identified by their title
+    pub fn record(&mut self, note: &Note) {
+        if !self.notes.iter().any(|read| read.title == note.title) {
+            self.notes.push(note.clone());
+        }
+    }
+}
+
+fn spawn_journal_menu(mut commands: Commands, journal: Res<Journal>) {
+    let mut menu = commands.spawn((
+        widget::ui_root("Journal Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Journal),
+        children![widget::header("Journal")],
+    ));
+    if journal.notes.is_empty() {
+        menu.with_child(widget::label("No notes read yet"));
+    }
+    for note in &journal.notes {
+        let note = note.clone();
+        menu.with_child(widget::button(
+            note.title.clone(),
+            move |_: On<Pointer<Click>>, mut commands: Commands| {
+                commands.trigger(ReadNote {
+                    note: note.clone(),
+                    return_to: Menu::Journal,
+                });
+            },
+        ));
+    }
+    menu.with_child(widget::button("Back", go_back_on_click));
+}
+
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Pause);
+}
+
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Pause);
+}
@@ -1,18 +1,25 @@
 //! The game's menus and transitions between them.
 
 mod credits;
+mod journal;
 mod main;
+mod note;
 mod pause;
 mod settings;
 
 use crate::prelude::*;
 
+pub use journal::Journal;
+pub use note::ReadNote;
+
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Menu>();
 
     app.add_plugins((
         credits::plugin,
+        journal::plugin,
         main::plugin,
+        note::plugin,
         settings::plugin,
         pause::plugin,
     ));
@@ -26,4 +33,6 @@ pub enum Menu {
     Credits,
     Settings,
     Pause,
+    Journal,
+    Note,
 }
@@ -0,0 +1,185 @@
+//! The paper-style note reader, see [`ReadNoteInteraction`].
+
+use feverdream_trap_core::prelude::cursor::{cursor_grab, cursor_ungrab};
+
+use crate::{menus::Journal, prelude::*};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<OpenNote>()
+        .add_observer(on_read_note)
+        .add_systems(OnEnter(Menu::Note), (spawn_note, cursor_ungrab))
+        .add_systems(
+            Update,
+            (
+                go_back.run_if(input_just_pressed(KeyCode::Escape)),
+                previous_page.run_if(
+                    input_just_pressed(KeyCode::ArrowLeft).or(input_just_pressed(KeyCode::KeyA)),
+                ),
+                next_page.run_if(
+                    input_just_pressed(KeyCode::ArrowRight).or(input_just_pressed(KeyCode::KeyD)),
+                ),
+            )
+                .run_if(in_state(Menu::Note)),
+        )
+        .add_systems(
+            OnExit(Menu::Note),
+            cursor_grab.run_if(in_state(Screen::Gameplay).and(in_state(Menu::None))),
+        );
+}
+
+/// Open `note` on top of `return_to`, recording it in the [`Journal`]
+#[derive(Debug, Event)]
+pub struct ReadNote {
+    pub note: Note,
+    pub return_to: Menu,
+}
+
+/// The note being read
+#[derive(Debug, Default, Resource)]
+struct OpenNote {
+    note: Note,
+    page: usize,
+    return_to: Menu,
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct NoteUi;
+
+/// #f2e8cf
+const PAPER: Color = Color::srgb(0.949, 0.910, 0.812);
+/// #2b2118
+const INK: Color = Color::srgb(0.169, 0.129, 0.094);
+
+fn on_read_note(
+    event: On<ReadNote>,
+    mut open_note: ResMut<OpenNote>,
+    mut journal: ResMut<Journal>,
+    mut next_menu: ResMut<NextState<Menu>>,
+    mut next_pause: ResMut<NextState<Pause>>,
+) {
+    journal.record(&event.note);
+    *open_note = OpenNote {
+        note: event.note.clone(),
+        page: 0,
+        return_to: event.return_to,
+    };
+    next_menu.set(Menu::Note);
+    // Like the pause menu, so nothing happens to the player while reading
+    next_pause.set(Pause(true));
+}
+
+fn spawn_note(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    open_note: Res<OpenNote>,
+    note_ui: Query<Entity, With<NoteUi>>,
+) {
+    for entity in &note_ui {
+        commands.entity(entity).despawn();
+    }
+
+    let page = open_note
+        .note
+        .pages
+        .get(open_note.page)
+        .cloned()
+        .unwrap_or_default();
+    let page_count = open_note.note.pages.len().max(1);
+
+    let mut paper = commands.spawn((
+        Name::new("Paper"),
+        Node {
+            width: px(600),
+            min_height: px(700),
+            padding: UiRect::all(px(40)),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(20),
+            ..default()
+        },
+        BackgroundColor(PAPER),
+        children![
+            (
+                Name::new("Title"),
+                Text(open_note.note.title.clone()),
+                TextFont::from_font_size(32.),
+                TextColor(INK),
+            ),
+            (
+                Name::new("Text"),
+                Text(page.text),
+                TextFont::from_font_size(22.),
+                TextColor(INK),
+            ),
+        ],
+    ));
+    if !page.image.is_empty() {
+        paper.with_child((
+            Name::new("Image"),
+            ImageNode::new(asset_server.load(page.image)),
+            Node {
+                max_width: percent(100),
+                ..default()
+            },
+        ));
+    }
+    let paper = paper.id();
+
+    commands
+        .spawn((
+            widget::ui_root("Note"),
+            NoteUi,
+            GlobalZIndex(2),
+            BackgroundColor(Color::srgba(0., 0., 0., 0.8)),
+            DespawnOnExit(Menu::Note),
+            children![(
+                Name::new("Page Navigation"),
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: px(20),
+                    ..default()
+                },
+                children![
+                    widget::button_small("<", previous_page_on_click),
+                    widget::label(format!("{} / {page_count}", open_note.page + 1)),
+                    widget::button_small(">", next_page_on_click),
+                    widget::button_small("Close", go_back_on_click),
+                ],
+            )],
+        ))
+        .insert_children(0, &[paper]);
+}
+
+fn previous_page(mut commands: Commands, mut open_note: ResMut<OpenNote>) {
+    if open_note.page > 0 {
+        open_note.page -= 1;
+        commands.run_system_cached(spawn_note);
+    }
+}
+
+fn next_page(mut commands: Commands, mut open_note: ResMut<OpenNote>) {
+    if open_note.page + 1 < open_note.note.pages.len() {
+        open_note.page += 1;
+        commands.run_system_cached(spawn_note);
+    }
+}
+
+fn previous_page_on_click(_: On<Pointer<Click>>, commands: Commands, open_note: ResMut<OpenNote>) {
+    previous_page(commands, open_note);
+}
+
+fn next_page_on_click(_: On<Pointer<Click>>, commands: Commands, open_note: ResMut<OpenNote>) {
+    next_page(commands, open_note);
+}
+
+fn go_back(open_note: Res<OpenNote>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(open_note.return_to);
+}
+
+fn go_back_on_click(
+    _: On<Pointer<Click>>,
+    open_note: Res<OpenNote>,
+    next_menu: ResMut<NextState<Menu>>,
+) {
+    go_back(open_note, next_menu);
+}
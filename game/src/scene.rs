@@ -3,10 +3,13 @@ use bevy::scene::SceneInstanceReady;
 use bevy::tasks::IoTaskPool;
 
 use crate::camera_controller::{
-    CameraMarker, CameraStatusEffects, CameraTargetCharacterController, spawn_camera,
+    CameraMarker, CameraStatusEffects, CameraTargetCharacterController, DrugInteraction,
+    spawn_camera, start_level_status_effects,
 };
-use crate::character_controller::{Player, PlayerInput, spawn_player};
-use crate::interaction::Interactable;
+use crate::character_controller::{Player, PlayerInput, Sanity, spawn_player};
+use crate::chunk::RunSeed;
+use crate::interaction::{DoorState, ElevatorRide, Interactable, Inventory};
+use crate::menus::Journal;
 use crate::prelude::*;
 use crate::utils::audio::MusicMarker;
 use bevy::ecs::system::RunSystemOnce;
@@ -72,6 +75,11 @@ fn save_scene(
             // Allowed resources
             //
             .allow_resource::<CameraStatusEffects>()
+            .allow_resource::<RunSeed>()
+            .allow_resource::<Inventory>()
+            .allow_resource::<Sanity>()
+            .allow_resource::<StoryState>()
+            .allow_resource::<Journal>()
             //
             // Allowed components
             //
@@ -96,20 +104,52 @@ fn save_scene(
             // Chunks
             .allow_component::<Chunk>()
             .allow_component::<ChunkId>()
+            .allow_component::<ChunkDescriptorHistory>()
+            .allow_component::<ChunkElementInstance>()
+            .allow_component::<Dormant>()
             // Chunk Components
             .allow_component::<SpawnMarker>()
             .allow_component::<ChunkLight>()
             .allow_component::<SwapSensorChunk>()
             .allow_component::<ReplaceAssetSensorChunk>()
+            .allow_component::<RevertAssetSensorChunk>()
             .allow_component::<MoveChunkSensorChunk>()
+            .allow_component::<MutationPoolSensorChunk>()
+            .allow_component::<MutationPoolRolls>()
+            .allow_component::<DormancySensorChunk>()
+            .allow_component::<TeleportSensorChunk>()
+            .allow_component::<DoorSensorChunk>()
+            .allow_component::<SanityZoneChunk>()
+            .allow_component::<ChunkDoor>()
             // Relationships
             .allow_component::<Children>()
             .allow_component::<ChildOf>()
             // Interactions
             .allow_component::<Interactable>()
+            .allow_component::<InteractionPrompt>()
+            .allow_component::<DebugInteraction>()
             .allow_component::<DespawnInteraction>()
             .allow_component::<SwapChunksInteraction>()
             .allow_component::<PlaySoundEffectInteraction>()
+            .allow_component::<PickupInteraction>()
+            .allow_component::<RequiresItem>()
+            .allow_component::<HoldToInteract>()
+            .allow_component::<InteractionDistance>()
+            .allow_component::<InteractionStages>()
+            .allow_component::<CarryInteraction>()
+            .allow_component::<PropSocket>()
+            .allow_component::<StoryInteraction>()
+            .allow_component::<ReadNoteInteraction>()
+            .allow_component::<StoryConditions>()
+            .allow_component::<StorySensorChunk>()
+            .allow_component::<ElevatorArrivalChunk>()
+            .allow_component::<ElevatorDoors>()
+            .allow_component::<DoorInteraction>()
+            .allow_component::<ElevatorInteraction>()
+            .allow_component::<PickupFuseInteraction>()
+            // Its `DrugHandle` is loaded again when the interaction is added
+            .allow_component::<DrugInteraction>()
+            .allow_component::<DoorState>()
             // Audio
             .allow_component::<MusicMarker>()
             //
@@ -146,14 +186,17 @@ fn save_scene(
     }
 }
 
-fn spawn_scene(mut commands: Commands, mut game_scene: ResMut<GameSceneStorage>) {
+fn spawn_scene(
+    mut commands: Commands,
+    mut game_scene: ResMut<GameSceneStorage>,
+    ride: Option<Res<ElevatorRide>>,
+) {
     // Reset skip_save flag for this gameplay session
     game_scene.skip_save = false;
 
-    commands.init_resource::<CameraStatusEffects>();
-
     if let Some(handle) = &game_scene.handle {
-        // Load saved scene
+        // Load saved scene, its status effects replace these once spawned
+        commands.init_resource::<CameraStatusEffects>();
         commands
             .spawn((
                 Name::new("Game scene spawner"),
@@ -167,7 +210,16 @@ fn spawn_scene(mut commands: Commands, mut game_scene: ResMut<GameSceneStorage>)
             });
     } else {
         // No saved scene, spawn from layout
+        // The elevator carries the run's state to the next level, anything else starts a new run
+        if ride.is_none() {
+            commands.insert_resource(RunSeed::default());
+            commands.insert_resource(Sanity::default());
+            commands.insert_resource(Inventory::default());
+            commands.insert_resource(StoryState::default());
+            commands.insert_resource(Journal::default());
+        }
         commands.queue(|world: &mut World| {
+            let _ = world.run_system_once(start_level_status_effects);
             let _ = world.run_system_once(spawn_level_from_layout);
             let _ = world.run_system_once(spawn_camera);
             let _ = world.run_system_once(spawn_player);
@@ -12,11 +12,25 @@ pub(super) fn plugin(app: &mut App) {
         );
 }
 
-fn spawn_game_over_screen(mut commands: Commands) {
+/// Why the run ended, shown on the game over screen
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverReason {
+    #[default]
+    Escaped,
+    /// A status effect crossed a succumb threshold
+    Succumbed,
+}
+
+fn spawn_game_over_screen(mut commands: Commands, reason: Option<Res<GameOverReason>>) {
+    let text = match reason.as_deref().copied().unwrap_or_default() {
+        GameOverReason::Escaped => "YOU ESCAPED!",
+        GameOverReason::Succumbed => "YOU SUCCUMBED",
+    };
+    commands.remove_resource::<GameOverReason>();
     commands.spawn((
         widget::ui_root("Game Over Screen"),
         DespawnOnExit(Screen::GameOver),
-        children![widget::label("YOU ESCAPED!")],
+        children![widget::label(text)],
     ));
 }
 
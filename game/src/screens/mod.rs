@@ -6,6 +6,8 @@ mod loading;
 mod splash;
 mod title;
 
+pub use game_over::GameOverReason;
+
 use crate::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {